@@ -0,0 +1,145 @@
+use crate::solution::{Answer, Part};
+use crate::utils;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Pass,
+    Fail,
+    Unknown,
+}
+
+impl Verdict {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Verdict::Pass => "pass",
+            Verdict::Fail => "fail",
+            Verdict::Unknown => "unknown",
+        }
+    }
+}
+
+/// The known correct answers for the real input of a day, stored next to it in
+/// `inputs/<year>/day_N/answers` as `part 1: <answer>` and `part 2: <answer>` lines.
+#[derive(Debug, Default, Eq, PartialEq)]
+pub struct Answers(BTreeMap<Part, String>);
+
+pub fn path(year: u16, day: usize) -> String {
+    format!("{}/answers", utils::day_dir(year, day))
+}
+
+/// The answers of the examples, in the same format as the answers of the real input.
+pub fn example_path(year: u16, day: usize) -> String {
+    format!("{}/test_answers", utils::day_dir(year, day))
+}
+
+impl Answers {
+    /// Loads the answers of a day, a missing file means none of them are known yet.
+    pub fn load(year: u16, day: usize) -> Result<Answers, String> {
+        Answers::read(&path(year, day))
+    }
+
+    pub fn save(&self, year: u16, day: usize) -> Result<(), String> {
+        self.write(&path(year, day))
+    }
+
+    pub fn read(path: &str) -> Result<Answers, String> {
+        if !Path::new(path).exists() {
+            return Ok(Answers::default());
+        }
+
+        let content =
+            fs::read_to_string(path).map_err(|e| format!("Failed to read {}: {}", path, e))?;
+
+        Answers::parse(&content).map_err(|e| format!("{} is invalid: {}", path, e))
+    }
+
+    pub fn write(&self, path: &str) -> Result<(), String> {
+        fs::write(path, self.to_string()).map_err(|e| format!("Failed to write {}: {}", path, e))
+    }
+
+    pub fn parse(content: &str) -> Result<Answers, String> {
+        let mut answers = Answers::default();
+
+        for (i, line) in content.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let (part, answer) = match line.split_once(':') {
+                Some(("part 1", answer)) => (Part::One, answer),
+                Some(("part 2", answer)) => (Part::Two, answer),
+                _ => {
+                    return Err(format!(
+                        "line {} should look like `part 1: <answer>`",
+                        i + 1
+                    ))
+                }
+            };
+
+            answers.set(part, answer.trim());
+        }
+
+        Ok(answers)
+    }
+
+    pub fn get(&self, part: Part) -> Option<&str> {
+        self.0.get(&part).map(String::as_str)
+    }
+
+    pub fn set(&mut self, part: Part, answer: &str) {
+        self.0.insert(part, answer.to_string());
+    }
+
+    pub fn check(&self, part: Part, answer: &Answer) -> Verdict {
+        match self.get(part) {
+            Some(expected) if expected == answer.to_string() => Verdict::Pass,
+            Some(_) => Verdict::Fail,
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+impl std::fmt::Display for Answers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (part, answer) in &self.0 {
+            writeln!(f, "part {}: {}", part, answer)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_parse_and_check() {
+        let answers = Answers::parse("part 1: 4,6,3,5\n\npart 2: 117440\n").unwrap();
+
+        assert_eq!(
+            answers.check(Part::One, &Answer::from("4,6,3,5")),
+            Verdict::Pass
+        );
+        assert_eq!(
+            answers.check(Part::Two, &Answer::from(117441usize)),
+            Verdict::Fail
+        );
+        assert_eq!(
+            Answers::default().check(Part::One, &Answer::from(1usize)),
+            Verdict::Unknown
+        );
+        assert_eq!(answers.to_string(), "part 1: 4,6,3,5\npart 2: 117440\n");
+    }
+
+    #[test]
+    pub fn test_parse_errors() {
+        assert!(Answers::parse("part 3: 12").is_err());
+        assert!(Answers::parse("12").is_err());
+    }
+}
@@ -0,0 +1,304 @@
+use crate::report::Format;
+use crate::solution::Part;
+use crate::utils;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt::{Display, Formatter};
+use std::hint::black_box;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+/// Runs per step when neither a run count nor a time budget is given.
+const DEFAULT_RUNS: u32 = 10;
+
+pub struct Config {
+    pub runs: Option<u32>,
+    pub budget: Option<Duration>,
+    pub warmup: u32,
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Step {
+    Parse,
+    Part(Part),
+}
+
+impl Display for Step {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Step::Parse => write!(f, "parse"),
+            Step::Part(part) => write!(f, "part {}", part),
+        }
+    }
+}
+
+impl FromStr for Step {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "parse" => Ok(Step::Parse),
+            "part 1" => Ok(Step::Part(Part::One)),
+            "part 2" => Ok(Step::Part(Part::Two)),
+            _ => Err(format!("`{}` is not a benchmark step", s)),
+        }
+    }
+}
+
+impl Serialize for Step {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Step {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub max: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+    /// Samples outside of the Tukey fences, 1.5 times the interquartile range below the first or
+    /// above the third quartile.
+    pub outliers: usize,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        assert!(
+            !samples.is_empty(),
+            "Cannot calculate stats without samples"
+        );
+
+        let mut sorted: Vec<f64> = samples.iter().map(|s| s.as_secs_f64()).collect();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+
+        let runs = sorted.len();
+        let mean = sorted.iter().sum::<f64>() / runs as f64;
+        let variance = if runs > 1 {
+            sorted.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / (runs - 1) as f64
+        } else {
+            0.0
+        };
+
+        let q1 = quantile(&sorted, 0.25);
+        let q3 = quantile(&sorted, 0.75);
+        let fence = 1.5 * (q3 - q1);
+        let outliers = sorted
+            .iter()
+            .filter(|s| **s < q1 - fence || **s > q3 + fence)
+            .count();
+
+        Stats {
+            runs,
+            min: Duration::from_secs_f64(sorted[0]),
+            max: Duration::from_secs_f64(sorted[runs - 1]),
+            median: Duration::from_secs_f64(quantile(&sorted, 0.5)),
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+            outliers,
+        }
+    }
+}
+
+/// Linear interpolation between the closest ranks of an already sorted list.
+fn quantile(sorted: &[f64], q: f64) -> f64 {
+    let rank = q * (sorted.len() - 1) as f64;
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - lower as f64)
+}
+
+/// Calls `f` for the warmup runs, then measures it until the run count or time budget is
+/// reached. With a time budget the run count is an upper limit, at least one run is measured.
+pub fn sample<T, F: FnMut() -> T>(config: &Config, mut f: F) -> Vec<Duration> {
+    for _ in 0..config.warmup {
+        black_box(f());
+    }
+
+    let max_runs = match (config.runs, config.budget) {
+        (Some(runs), _) => runs,
+        (None, Some(_)) => u32::MAX,
+        (None, None) => DEFAULT_RUNS,
+    };
+
+    let start = Instant::now();
+    let mut samples: Vec<Duration> = vec![];
+
+    while samples.len() < max_runs as usize {
+        let (result, took) = utils::time_it(&mut f);
+        black_box(result);
+
+        samples.push(took);
+
+        if config
+            .budget
+            .is_some_and(|budget| start.elapsed() >= budget)
+        {
+            break;
+        }
+    }
+
+    samples
+}
+
+pub struct BenchReport {
+    pub year: u16,
+    pub day: usize,
+    pub step: Step,
+    pub stats: Stats,
+}
+
+#[derive(Serialize)]
+struct Record {
+    year: u16,
+    day: usize,
+    step: Step,
+    runs: usize,
+    min_ns: u128,
+    median_ns: u128,
+    mean_ns: u128,
+    stddev_ns: u128,
+    max_ns: u128,
+    outliers: usize,
+}
+
+impl From<&BenchReport> for Record {
+    fn from(report: &BenchReport) -> Self {
+        Record {
+            year: report.year,
+            day: report.day,
+            step: report.step,
+            runs: report.stats.runs,
+            min_ns: report.stats.min.as_nanos(),
+            median_ns: report.stats.median.as_nanos(),
+            mean_ns: report.stats.mean.as_nanos(),
+            stddev_ns: report.stats.stddev.as_nanos(),
+            max_ns: report.stats.max.as_nanos(),
+            outliers: report.stats.outliers,
+        }
+    }
+}
+
+pub fn print_reports(reports: &[BenchReport], format: Format) {
+    match format {
+        Format::Text => {
+            println!(
+                "{:<4} {:<7} {:>6} {:>12} {:>12} {:>12} {:>12} {:>12} {:>9}",
+                "Day", "Step", "Runs", "Min", "Median", "Mean", "Std dev", "Max", "Outliers"
+            );
+
+            for report in reports {
+                let stats = &report.stats;
+
+                println!(
+                    "{:<4} {:<7} {:>6} {:>12} {:>12} {:>12} {:>12} {:>12} {:>9}",
+                    report.day,
+                    report.step.to_string(),
+                    stats.runs,
+                    utils::format_duration(stats.min),
+                    utils::format_duration(stats.median),
+                    utils::format_duration(stats.mean),
+                    utils::format_duration(stats.stddev),
+                    utils::format_duration(stats.max),
+                    stats.outliers
+                );
+            }
+        }
+        Format::Json => {
+            let records: Vec<Record> = reports.iter().map(Record::from).collect();
+
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&records).expect("Records are valid JSON")
+            );
+        }
+        Format::Csv => {
+            println!("year,day,step,runs,min_ns,median_ns,mean_ns,stddev_ns,max_ns,outliers");
+
+            for record in reports.iter().map(Record::from) {
+                println!(
+                    "{},{},{},{},{},{},{},{},{},{}",
+                    record.year,
+                    record.day,
+                    record.step,
+                    record.runs,
+                    record.min_ns,
+                    record.median_ns,
+                    record.mean_ns,
+                    record.stddev_ns,
+                    record.max_ns,
+                    record.outliers
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(samples: &[u64]) -> Vec<Duration> {
+        samples.iter().map(|s| Duration::from_millis(*s)).collect()
+    }
+
+    #[test]
+    pub fn test_stats() {
+        let stats = Stats::from_samples(&ms(&[4, 2, 3, 5, 1]));
+
+        assert_eq!(stats.runs, 5);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.max, Duration::from_millis(5));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.mean, Duration::from_millis(3));
+        assert_eq!(stats.stddev.as_micros(), 1581);
+        assert_eq!(stats.outliers, 0);
+    }
+
+    #[test]
+    pub fn test_stats_outliers() {
+        let stats = Stats::from_samples(&ms(&[10, 11, 10, 12, 11, 10, 50]));
+
+        assert_eq!(stats.median, Duration::from_millis(11));
+        assert_eq!(stats.outliers, 1);
+    }
+
+    #[test]
+    pub fn test_single_sample() {
+        let stats = Stats::from_samples(&ms(&[7]));
+
+        assert_eq!(stats.median, Duration::from_millis(7));
+        assert_eq!(stats.stddev, Duration::ZERO);
+    }
+
+    #[test]
+    pub fn test_sample_counts() {
+        let mut calls = 0;
+        let config = Config {
+            runs: Some(4),
+            budget: None,
+            warmup: 2,
+        };
+
+        assert_eq!(sample(&config, || calls += 1).len(), 4);
+        assert_eq!(calls, 6);
+
+        let config = Config {
+            runs: Some(1000),
+            budget: Some(Duration::ZERO),
+            warmup: 0,
+        };
+
+        assert_eq!(sample(&config, || ()).len(), 1);
+    }
+}
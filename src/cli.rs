@@ -0,0 +1,382 @@
+use crate::context::Level;
+use crate::registry;
+use crate::report::Format;
+use crate::solution::Part;
+use clap::{Args, Parser, Subcommand};
+use std::path::PathBuf;
+use std::time::Duration;
+
+#[derive(Debug, Parser)]
+#[command(
+    about = "Advent of Code solutions",
+    args_conflicts_with_subcommands = true
+)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
+    /// The event to use, defaults to the latest year with solutions
+    #[arg(short, long, global = true, value_parser = clap::value_parser!(u16).range(2015..))]
+    pub year: Option<u16>,
+
+    /// Running without a subcommand is the same as `run`, so `cargo run -- 3` keeps working
+    #[command(flatten)]
+    pub run: RunArgs,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Solve the selected days using the real input
+    Run(RunArgs),
+    /// Time parsing and every part repeatedly and report statistics about the runs
+    Bench(BenchArgs),
+    /// Compare the benchmark medians of two commits and flag the steps that got slower
+    Compare(CompareArgs),
+    /// Download the real input for the selected days
+    Download(DownloadArgs),
+    /// Solve a part and submit the answer to adventofcode.com
+    Submit(SubmitArgs),
+    /// Run the example tests of the selected days, like `cargo test` does
+    Test(TestArgs),
+    /// Create the module of a new day from a template, register it and prepare its input directory
+    New(NewArgs),
+    /// Assemble a day 17 program from mnemonic source into an input, or disassemble an input
+    Assemble(AssembleArgs),
+}
+
+#[derive(Args, Debug)]
+pub struct RunArgs {
+    #[command(flatten)]
+    pub selection: SelectionArgs,
+
+    /// How the answers and timings are written to stdout
+    #[arg(short, long, value_enum, default_value_t = Format::Text)]
+    pub format: Format,
+
+    /// Store the answers of parts that have no known answer yet in `inputs/<year>/day_N/answers`
+    #[arg(long, conflicts_with = "input")]
+    pub save_answers: bool,
+
+    /// Give up on a day that takes longer than this many seconds, the other days still run
+    #[arg(short, long, value_parser = parse_seconds)]
+    pub timeout: Option<Duration>,
+
+    /// Solve this many days at the same time, the output stays in the order of the days
+    #[arg(short, long, default_value_t = 1, value_parser = parse_jobs)]
+    pub jobs: usize,
+
+    /// Show the messages the solutions log up to this level, `trace` includes the frames they draw
+    #[arg(short, long, value_enum, default_value_t = Level::Info)]
+    pub log_level: Level,
+
+    /// Only print the answers and errors, without the logs of the solutions
+    #[arg(short, long, conflicts_with = "log_level")]
+    pub quiet: bool,
+
+    /// Animate the frames the solutions draw in the terminal, the days run one at a time. A paused
+    /// animation would count against --timeout, so the two cannot be combined
+    #[arg(long, conflicts_with_all = ["jobs", "timeout"])]
+    pub visualize: bool,
+
+    /// Stop where a condition starts to hold, in the solutions that support it, like `ip == 4` or
+    /// `A < 8` for day 17. The animation pauses there, without --visualize the state is logged
+    #[arg(long = "break", value_name = "CONDITION")]
+    pub breakpoints: Vec<String>,
+
+    /// How many frames a second are shown with --visualize
+    #[arg(
+        long,
+        default_value_t = 30,
+        requires = "visualize",
+        value_parser = clap::value_parser!(u32).range(1..=1000)
+    )]
+    pub fps: u32,
+}
+
+#[derive(Args, Debug)]
+pub struct SelectionArgs {
+    /// Days to run, like `3`, `1-5` or `1-5,9,12`. Runs every day when omitted
+    #[arg(value_parser = parse_days)]
+    pub days: Option<Days>,
+
+    /// Only run a single part
+    #[arg(short, long, value_parser = parse_part)]
+    pub part: Option<Part>,
+
+    /// Read the input from this file instead, use `-` to read from stdin. Requires a single day
+    #[arg(short, long, value_parser = parse_input_source)]
+    pub input: Option<InputSource>,
+}
+
+#[derive(Args, Debug)]
+pub struct BenchArgs {
+    #[command(flatten)]
+    pub selection: SelectionArgs,
+
+    /// How many measured runs every step gets, defaults to 10. With --time this is the maximum
+    #[arg(short, long, value_parser = clap::value_parser!(u32).range(1..))]
+    pub runs: Option<u32>,
+
+    /// Keep measuring every step until this many seconds are used up
+    #[arg(short, long, value_parser = parse_seconds)]
+    pub time: Option<Duration>,
+
+    /// Unmeasured runs of every step before measuring starts
+    #[arg(short, long, default_value_t = 3)]
+    pub warmup: u32,
+
+    /// How the statistics are written to stdout
+    #[arg(short, long, value_enum, default_value_t = Format::Text)]
+    pub format: Format,
+
+    /// Do not add the results to the benchmark history. Runs with --input are never added
+    #[arg(long)]
+    pub no_save: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct CompareArgs {
+    /// Commit to compare against, defaults to the last benchmarked commit before the target
+    #[arg(long)]
+    pub base: Option<String>,
+
+    /// Commit to check for regressions, defaults to the last benchmarked commit
+    #[arg(long)]
+    pub target: Option<String>,
+
+    /// Percentage a median may slow down before the step counts as regressed
+    #[arg(short, long, default_value_t = 10.0)]
+    pub threshold: f64,
+
+    /// Machine the benchmarks ran on, defaults to this machine
+    #[arg(short, long)]
+    pub machine: Option<String>,
+}
+
+#[derive(Args, Debug)]
+pub struct DownloadArgs {
+    /// Days to download, like `3`, `1-5` or `1-5,9,12`. Downloads every day when omitted
+    #[arg(value_parser = parse_days)]
+    pub days: Option<Days>,
+
+    /// Download the input again, even when it already exists. With --examples the puzzle page is
+    /// downloaded again and the test files are overwritten as well
+    #[arg(short, long)]
+    pub force: bool,
+
+    /// Also download the puzzle page and extract its examples into `test` and `p2_test`, with
+    /// their answers in `test_answers`
+    #[arg(short, long)]
+    pub examples: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct SubmitArgs {
+    /// The day to submit
+    #[arg(value_parser = parse_day)]
+    pub day: usize,
+
+    /// The part to submit
+    #[arg(value_parser = parse_part)]
+    pub part: Part,
+
+    /// Submit this answer instead of solving the part with the real input
+    #[arg(short, long)]
+    pub answer: Option<String>,
+}
+
+#[derive(Args, Debug)]
+pub struct TestArgs {
+    /// Days to test, like `3`, `1-5` or `1-5,9,12`. Tests every day when omitted
+    #[arg(value_parser = parse_days)]
+    pub days: Option<Days>,
+
+    /// Only run a single part
+    #[arg(short, long, value_parser = parse_part)]
+    pub part: Option<Part>,
+}
+
+#[derive(Args, Debug)]
+pub struct NewArgs {
+    /// The day to prepare
+    #[arg(value_parser = parse_day)]
+    pub day: usize,
+
+    /// Replace the module of the day when it already exists
+    #[arg(short, long)]
+    pub force: bool,
+
+    /// Also download the input and the puzzle page, and extract the examples into the test files
+    #[arg(short, long)]
+    pub download: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct AssembleArgs {
+    /// The source to assemble, or the input with --disassemble. Use `-` to read from stdin
+    #[arg(value_parser = parse_input_source)]
+    pub file: InputSource,
+
+    /// Turn an input into source that assembles back to the same input
+    #[arg(short, long)]
+    pub disassemble: bool,
+}
+
+/// A sorted list of unique days, parsed from a selection like `1-5,9,12`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Days(pub Vec<usize>);
+
+impl Days {
+    pub fn all() -> Days {
+        Days((1..=registry::DAY_COUNT).collect())
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+}
+
+fn parse_day(value: &str) -> Result<usize, String> {
+    let day: usize = value
+        .trim()
+        .parse()
+        .map_err(|_| format!("`{}` is not a day number", value.trim()))?;
+
+    if day == 0 || day > registry::DAY_COUNT {
+        return Err(format!(
+            "day {} does not exist, days range from 1 to {}",
+            day,
+            registry::DAY_COUNT
+        ));
+    }
+
+    Ok(day)
+}
+
+fn parse_days(value: &str) -> Result<Days, String> {
+    let mut days: Vec<usize> = vec![];
+
+    for selection in value.split(',') {
+        if let Some((start, end)) = selection.split_once('-') {
+            let start = parse_day(start)?;
+            let end = parse_day(end)?;
+
+            if start > end {
+                return Err(format!(
+                    "range `{}` ends before it starts",
+                    selection.trim()
+                ));
+            }
+
+            days.extend(start..=end);
+        } else {
+            days.push(parse_day(selection)?);
+        }
+    }
+
+    days.sort();
+    days.dedup();
+
+    Ok(Days(days))
+}
+
+fn parse_part(value: &str) -> Result<Part, String> {
+    match value {
+        "1" => Ok(Part::One),
+        "2" => Ok(Part::Two),
+        _ => Err(format!("part `{}` does not exist, use 1 or 2", value)),
+    }
+}
+
+fn parse_seconds(value: &str) -> Result<Duration, String> {
+    value
+        .parse::<f64>()
+        .ok()
+        .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+        .filter(|duration| !duration.is_zero())
+        .ok_or_else(|| format!("`{}` is not a positive number of seconds", value))
+}
+
+fn parse_jobs(value: &str) -> Result<usize, String> {
+    value
+        .parse::<usize>()
+        .ok()
+        .filter(|jobs| *jobs > 0)
+        .ok_or_else(|| format!("`{}` is not a positive number of jobs", value))
+}
+
+fn parse_input_source(value: &str) -> Result<InputSource, String> {
+    if value == "-" {
+        return Ok(InputSource::Stdin);
+    }
+
+    let path = PathBuf::from(value);
+    if !path.is_file() {
+        return Err(format!("input file `{}` does not exist", value));
+    }
+
+    Ok(InputSource::File(path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_parse_days() {
+        assert_eq!(parse_days("3"), Ok(Days(vec![3])));
+        assert_eq!(parse_days("1-5,9,12"), Ok(Days(vec![1, 2, 3, 4, 5, 9, 12])));
+        assert_eq!(parse_days("4,2-3,3"), Ok(Days(vec![2, 3, 4])));
+    }
+
+    #[test]
+    pub fn test_parse_days_errors() {
+        assert!(parse_days("0").is_err());
+        assert!(parse_days("26").is_err());
+        assert!(parse_days("5-3").is_err());
+        assert!(parse_days("one").is_err());
+        assert!(parse_days("1,").is_err());
+    }
+
+    #[test]
+    pub fn test_parse_seconds() {
+        assert_eq!(parse_seconds("2"), Ok(Duration::from_secs(2)));
+        assert!(parse_seconds("0").is_err());
+        assert!(parse_seconds("-1").is_err());
+        assert!(parse_seconds("soon").is_err());
+    }
+
+    #[test]
+    pub fn test_default_command() {
+        let cli = Cli::try_parse_from(["aoc", "3", "--part", "2"]).unwrap();
+
+        assert!(cli.command.is_none());
+        assert_eq!(cli.year, None);
+        assert_eq!(cli.run.selection.days, Some(Days(vec![3])));
+        assert_eq!(cli.run.selection.part, Some(Part::Two));
+        assert_eq!(cli.run.format, Format::Text);
+        assert!(!cli.run.save_answers);
+    }
+
+    #[test]
+    pub fn test_subcommand() {
+        let cli = Cli::try_parse_from([
+            "aoc", "bench", "1-2", "--runs", "5", "--time", "0.5", "--year", "2023",
+        ])
+        .unwrap();
+
+        assert_eq!(cli.year, Some(2023));
+
+        match cli.command {
+            Some(Command::Bench(args)) => {
+                assert_eq!(args.selection.days, Some(Days(vec![1, 2])));
+                assert_eq!(args.runs, Some(5));
+                assert_eq!(args.time, Some(Duration::from_millis(500)));
+                assert_eq!(args.warmup, 3);
+            }
+            _ => panic!("Expected the bench command"),
+        }
+    }
+}
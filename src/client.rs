@@ -0,0 +1,336 @@
+use crate::solution::Part;
+use reqwest::{RequestBuilder, StatusCode};
+use std::env;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const USER_AGENT: &str = "advent-of-code-helper";
+
+/// How often a request is retried when the server has a problem, the wait between the retries
+/// starts at the backoff and doubles every time.
+const DEFAULT_RETRIES: u32 = 3;
+const DEFAULT_BACKOFF: Duration = Duration::from_secs(1);
+/// Minimum time between two requests, so downloading every day does not hammer the server.
+const DEFAULT_MIN_INTERVAL: Duration = Duration::from_secs(1);
+
+#[derive(Debug)]
+pub enum ClientError {
+    MissingSession,
+    /// The server asks to log in, the session cookie is wrong or expired.
+    LoggedOut,
+    /// The puzzle does not exist or is not unlocked yet.
+    NotUnlocked,
+    Status(StatusCode),
+    Request(reqwest::Error),
+    Io {
+        path: PathBuf,
+        source: io::Error,
+    },
+}
+
+impl Display for ClientError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClientError::MissingSession => write!(
+                f,
+                "env var AOC_SESSION is not set, cannot talk to adventofcode.com"
+            ),
+            ClientError::LoggedOut => write!(
+                f,
+                "adventofcode.com asks to log in, the session in AOC_SESSION is wrong or expired"
+            ),
+            ClientError::NotUnlocked => write!(f, "The puzzle is not unlocked yet"),
+            ClientError::Status(status) => write!(f, "adventofcode.com responded with {}", status),
+            ClientError::Request(e) => write!(f, "Request to adventofcode.com failed: {}", e),
+            ClientError::Io { path, source } => {
+                write!(f, "Failed to write {}: {}", path.display(), source)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+impl From<ClientError> for String {
+    fn from(error: ClientError) -> Self {
+        error.to_string()
+    }
+}
+
+/// Talks to adventofcode.com, or to whatever `AOC_BASE_URL` points at so it can be tested
+/// against a local server.
+pub struct Client {
+    http: reqwest::Client,
+    base_url: String,
+    session: String,
+    retries: u32,
+    backoff: Duration,
+    min_interval: Duration,
+    next_request: Mutex<Option<Instant>>,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Client {
+        Client {
+            http: reqwest::Client::new(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            retries: DEFAULT_RETRIES,
+            backoff: DEFAULT_BACKOFF,
+            min_interval: DEFAULT_MIN_INTERVAL,
+            next_request: Mutex::new(None),
+        }
+    }
+
+    pub fn from_env() -> Result<Client, ClientError> {
+        let session = env::var("AOC_SESSION").map_err(|_| ClientError::MissingSession)?;
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.to_string());
+
+        Ok(Client::new(&base_url, &session))
+    }
+
+    #[cfg(test)]
+    pub fn with_retries(mut self, retries: u32, backoff: Duration) -> Client {
+        self.retries = retries;
+        self.backoff = backoff;
+        self
+    }
+
+    #[cfg(test)]
+    pub fn with_min_interval(mut self, min_interval: Duration) -> Client {
+        self.min_interval = min_interval;
+        self
+    }
+
+    pub async fn download_input(
+        &self,
+        year: u16,
+        day: usize,
+        path: &Path,
+    ) -> Result<(), ClientError> {
+        self.download(
+            &format!("{}/{}/day/{}/input", self.base_url, year, day),
+            path,
+        )
+        .await
+    }
+
+    /// Downloads the puzzle page of a day, it only has part 2 once part 1 is solved.
+    pub async fn download_puzzle(
+        &self,
+        year: u16,
+        day: usize,
+        path: &Path,
+    ) -> Result<(), ClientError> {
+        self.download(&format!("{}/{}/day/{}", self.base_url, year, day), path)
+            .await
+    }
+
+    /// Downloads `url` to `path`, creating the directories it needs. The file is only replaced
+    /// once the whole response is in, and holds exactly what the server sent.
+    async fn download(&self, url: &str, path: &Path) -> Result<(), ClientError> {
+        let body = self.send(|| self.http.get(url), true).await?;
+
+        let io_error = |source| ClientError::Io {
+            path: path.to_path_buf(),
+            source,
+        };
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(io_error)?;
+        }
+
+        let partial = path.with_extension("partial");
+        fs::write(&partial, body).map_err(io_error)?;
+        fs::rename(&partial, path).map_err(io_error)
+    }
+
+    /// Posts an answer and returns the HTML of the response page. It is never retried, a server
+    /// error or dropped connection can come after the answer was counted.
+    pub async fn submit(
+        &self,
+        year: u16,
+        day: usize,
+        part: Part,
+        answer: &str,
+    ) -> Result<String, ClientError> {
+        let url = format!("{}/{}/day/{}/answer", self.base_url, year, day);
+        let level = part.to_string();
+
+        self.send(
+            || {
+                self.http
+                    .post(&url)
+                    .form(&[("level", level.as_str()), ("answer", answer)])
+            },
+            false,
+        )
+        .await
+    }
+
+    /// Sends the request `build` creates and returns the body of a successful response. With
+    /// `retry`, server errors and failed connections are retried, which is only safe for GETs.
+    async fn send<F: Fn() -> RequestBuilder>(
+        &self,
+        build: F,
+        retry: bool,
+    ) -> Result<String, ClientError> {
+        let mut backoff = self.backoff;
+        let mut retries = 0;
+
+        loop {
+            self.throttle().await;
+
+            let result = build()
+                .header("User-Agent", USER_AGENT)
+                .header("Cookie", format!("session={}", self.session))
+                .send()
+                .await;
+
+            let failed = match &result {
+                Ok(response) => response.status().is_server_error(),
+                Err(e) => e.is_connect() || e.is_timeout(),
+            };
+
+            if retry && failed && retries < self.retries {
+                retries += 1;
+                tokio::time::sleep(backoff).await;
+                backoff *= 2;
+
+                continue;
+            }
+
+            let response = result.map_err(ClientError::Request)?;
+            let status = response.status();
+            let body = response.text().await.map_err(ClientError::Request)?;
+
+            if body.contains("Please log in") {
+                return Err(ClientError::LoggedOut);
+            }
+
+            return match status {
+                StatusCode::NOT_FOUND => Err(ClientError::NotUnlocked),
+                status if !status.is_success() => Err(ClientError::Status(status)),
+                _ => Ok(body),
+            };
+        }
+    }
+
+    /// Waits until `min_interval` has passed since the previous request. The slot is claimed
+    /// before waiting, so concurrent requests line up behind each other.
+    async fn throttle(&self) {
+        let wait = {
+            let mut next_request = self.next_request.lock().unwrap();
+            let now = Instant::now();
+            let at = next_request.map_or(now, |next| next.max(now));
+
+            *next_request = Some(at + self.min_interval);
+
+            at - now
+        };
+
+        if !wait.is_zero() {
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_server;
+
+    const LOGGED_OUT: &str =
+        "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n";
+
+    fn client(base_url: &str) -> Client {
+        Client::new(base_url, "secret")
+            .with_retries(2, Duration::from_millis(1))
+            .with_min_interval(Duration::ZERO)
+    }
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir()
+            .join(format!("aoc-client-{}-{}", name, std::process::id()))
+            .join("day_1")
+            .join("input")
+    }
+
+    #[tokio::test]
+    pub async fn test_download_retries_server_errors() {
+        let (base_url, server) =
+            mock_server::serve(vec![(503, "busy"), (500, "oops"), (200, "1 2\n3 4\n")]).await;
+        let path = temp_path("retry");
+
+        client(&base_url)
+            .download_input(2024, 1, &path)
+            .await
+            .unwrap();
+        let requests = server.await.unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1 2\n3 4\n");
+        assert_eq!(requests.len(), 3);
+        assert_eq!(requests[2].path, "/2024/day/1/input");
+        assert_eq!(requests[2].header("cookie"), Some("session=secret"));
+
+        fs::remove_dir_all(path.parent().unwrap().parent().unwrap()).unwrap();
+    }
+
+    #[tokio::test]
+    pub async fn test_download_errors() {
+        let (base_url, _) = mock_server::serve(vec![(400, LOGGED_OUT)]).await;
+        let result = client(&base_url)
+            .download_input(2024, 1, &temp_path("x"))
+            .await;
+        assert!(matches!(result, Err(ClientError::LoggedOut)));
+
+        let (base_url, _) = mock_server::serve(vec![(404, "Not found")]).await;
+        let result = client(&base_url)
+            .download_input(2024, 1, &temp_path("x"))
+            .await;
+        assert!(matches!(result, Err(ClientError::NotUnlocked)));
+
+        let (base_url, _) = mock_server::serve(vec![(502, "a"), (502, "b"), (502, "c")]).await;
+        let result = client(&base_url)
+            .download_input(2024, 1, &temp_path("x"))
+            .await;
+        assert!(matches!(
+            result,
+            Err(ClientError::Status(StatusCode::BAD_GATEWAY))
+        ));
+
+        assert!(!temp_path("x").exists());
+    }
+
+    #[tokio::test]
+    pub async fn test_submit_is_not_retried() {
+        let (base_url, server) = mock_server::serve(vec![(503, "busy")]).await;
+        let result = client(&base_url).submit(2024, 1, Part::One, "42").await;
+        let requests = server.await.unwrap();
+
+        assert!(matches!(
+            result,
+            Err(ClientError::Status(StatusCode::SERVICE_UNAVAILABLE))
+        ));
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "POST");
+    }
+
+    #[tokio::test]
+    pub async fn test_throttle() {
+        let client =
+            Client::new("http://localhost", "").with_min_interval(Duration::from_millis(20));
+        let start = Instant::now();
+
+        client.throttle().await;
+        client.throttle().await;
+        client.throttle().await;
+
+        assert!(start.elapsed() >= Duration::from_millis(40));
+    }
+}
@@ -0,0 +1,219 @@
+use crate::solution::Part;
+use crate::timing;
+use clap::ValueEnum;
+use std::sync::Mutex;
+
+/// How much of what a solution logs is kept, every level includes the ones above it.
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd, ValueEnum)]
+pub enum Level {
+    Off,
+    Error,
+    Warn,
+    #[default]
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Level {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Level::Off => "off",
+            Level::Error => "error",
+            Level::Warn => "warn",
+            Level::Info => "info",
+            Level::Debug => "debug",
+            Level::Trace => "trace",
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Log {
+    pub part: Option<Part>,
+    pub level: Level,
+    pub message: String,
+}
+
+/// A picture of the state of a solution, like the map after a step.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Frame {
+    pub part: Option<Part>,
+    pub caption: String,
+    pub text: String,
+    /// Set for the frame of a breakpoint, the animation pauses on it.
+    pub pause: bool,
+}
+
+/// Where the frames of a solution are shown.
+pub trait Sink: Send {
+    fn show(&mut self, frame: &Frame);
+}
+
+/// Handed to the parts of a solution, so they log and draw through the runner instead of printing
+/// themselves. Messages and frames are only built when they will be shown, and the time that takes
+/// is not counted in the timings.
+pub struct Context {
+    level: Level,
+    part: Mutex<Option<Part>>,
+    logs: Mutex<Vec<Log>>,
+    sink: Option<Mutex<Box<dyn Sink>>>,
+    breakpoints: Vec<String>,
+}
+
+impl Context {
+    pub fn new(level: Level, sink: Option<Box<dyn Sink>>) -> Context {
+        Context {
+            level,
+            part: Mutex::new(None),
+            logs: Mutex::new(vec![]),
+            sink: sink.map(Mutex::new),
+            breakpoints: vec![],
+        }
+    }
+
+    /// The conditions passed with `--break`, every solution decides which ones it understands.
+    pub fn with_breakpoints(mut self, breakpoints: Vec<String>) -> Context {
+        self.breakpoints = breakpoints;
+        self
+    }
+
+    pub fn breakpoints(&self) -> &[String] {
+        &self.breakpoints
+    }
+
+    /// A context that drops everything, for benchmarks and tests.
+    pub fn silent() -> Context {
+        Context::new(Level::Off, None)
+    }
+
+    pub fn enabled(&self, level: Level) -> bool {
+        level != Level::Off && level <= self.level
+    }
+
+    pub fn log<F: FnOnce() -> String>(&self, level: Level, message: F) {
+        if !self.enabled(level) {
+            return;
+        }
+
+        timing::paused(|| {
+            let log = Log {
+                part: *self.part.lock().unwrap(),
+                level,
+                message: message(),
+            };
+
+            self.logs.lock().unwrap().push(log);
+        });
+    }
+
+    pub fn debug<F: FnOnce() -> String>(&self, message: F) {
+        self.log(Level::Debug, message);
+    }
+
+    /// Whether frames are shown, for solutions that have to prepare something before drawing.
+    pub fn visualizing(&self) -> bool {
+        self.sink.is_some() || self.enabled(Level::Trace)
+    }
+
+    /// Shows a frame drawn by `draw`. Without a sink, frames are logged at the trace level.
+    pub fn frame<F: FnOnce() -> String>(&self, caption: &str, draw: F) {
+        self.show(caption, draw, false, Level::Trace);
+    }
+
+    /// Shows the frame of a breakpoint that was hit and pauses the animation on it. Without a
+    /// sink, it is logged at the info level.
+    pub fn breakpoint<F: FnOnce() -> String>(&self, caption: &str, draw: F) {
+        self.show(caption, draw, true, Level::Info);
+    }
+
+    fn show<F: FnOnce() -> String>(&self, caption: &str, draw: F, pause: bool, level: Level) {
+        match &self.sink {
+            Some(sink) => timing::paused(|| {
+                let frame = Frame {
+                    part: *self.part.lock().unwrap(),
+                    caption: caption.to_string(),
+                    text: draw(),
+                    pause,
+                };
+
+                sink.lock().unwrap().show(&frame);
+            }),
+            None => self.log(level, || format!("{}\n{}", caption, draw())),
+        }
+    }
+
+    /// Marks the part that is solved from now on, the logs after it belong to that part.
+    pub fn start(&self, part: Part) {
+        *self.part.lock().unwrap() = Some(part);
+    }
+
+    pub fn take_logs(&self) -> Vec<Log> {
+        std::mem::take(&mut *self.logs.lock().unwrap())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    struct Recorder(Arc<Mutex<Vec<Frame>>>);
+
+    impl Sink for Recorder {
+        fn show(&mut self, frame: &Frame) {
+            self.0.lock().unwrap().push(frame.clone());
+        }
+    }
+
+    #[test]
+    pub fn test_levels() {
+        let context = Context::new(Level::Debug, None);
+
+        context.start(Part::Two);
+        context.debug(|| "kept".to_string());
+        context.log(Level::Trace, || {
+            panic!("Messages above the level are not built")
+        });
+        context.frame("map", || panic!("Frames are logged at the trace level"));
+
+        assert_eq!(
+            context.take_logs(),
+            vec![Log {
+                part: Some(Part::Two),
+                level: Level::Debug,
+                message: "kept".to_string(),
+            }]
+        );
+        assert!(context.take_logs().is_empty());
+        assert!(!Context::silent().enabled(Level::Error));
+    }
+
+    #[test]
+    pub fn test_frames_go_to_the_sink() {
+        let frames = Arc::new(Mutex::new(vec![]));
+        let context = Context::new(Level::Off, Some(Box::new(Recorder(frames.clone()))));
+
+        assert!(context.visualizing());
+        context.frame("step 1", || "#.#".to_string());
+        context.breakpoint("ip == 4", || "#..".to_string());
+
+        assert_eq!(
+            *frames.lock().unwrap(),
+            vec![
+                Frame {
+                    part: None,
+                    caption: "step 1".to_string(),
+                    text: "#.#".to_string(),
+                    pause: false,
+                },
+                Frame {
+                    part: None,
+                    caption: "ip == 4".to_string(),
+                    text: "#..".to_string(),
+                    pause: true,
+                }
+            ]
+        );
+    }
+}
@@ -0,0 +1,306 @@
+use crate::bench::{BenchReport, Step};
+use crate::utils;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+use std::process::Command;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+pub const PATH: &str = ".aoc/bench-history";
+
+/// A single benchmarked step, stored as one JSON line in the history file.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Entry {
+    pub commit: String,
+    pub machine: String,
+    pub timestamp: u64,
+    pub year: u16,
+    pub day: usize,
+    pub step: Step,
+    pub runs: usize,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub mean_ns: u64,
+    pub stddev_ns: u64,
+}
+
+impl Entry {
+    pub fn new(report: &BenchReport, commit: &str, machine: &str, timestamp: u64) -> Entry {
+        Entry {
+            commit: commit.to_string(),
+            machine: machine.to_string(),
+            timestamp,
+            year: report.year,
+            day: report.day,
+            step: report.step,
+            runs: report.stats.runs,
+            min_ns: report.stats.min.as_nanos() as u64,
+            median_ns: report.stats.median.as_nanos() as u64,
+            mean_ns: report.stats.mean.as_nanos() as u64,
+            stddev_ns: report.stats.stddev.as_nanos() as u64,
+        }
+    }
+}
+
+/// The short hash of `HEAD`, with `-dirty` appended when tracked files have uncommitted changes
+/// so work in progress is never mistaken for the commit it is based on.
+pub fn current_commit() -> String {
+    let Some(commit) = git(&["rev-parse", "--short", "HEAD"]) else {
+        return "unknown".to_string();
+    };
+
+    match git(&["status", "--porcelain", "--untracked-files=no"]) {
+        Some(changes) if !changes.is_empty() => format!("{}-dirty", commit),
+        _ => commit,
+    }
+}
+
+/// `AOC_MACHINE` when set, otherwise the host name. Timings are only comparable on one machine.
+pub fn current_machine() -> String {
+    if let Ok(machine) = std::env::var("AOC_MACHINE") {
+        return machine;
+    }
+
+    Command::new("hostname")
+        .output()
+        .ok()
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+        .filter(|name| !name.is_empty())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
+
+fn git(args: &[&str]) -> Option<String> {
+    let output = Command::new("git").args(args).output().ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+pub fn append(path: &Path, entries: &[Entry]) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)
+            .map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+    }
+
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(|e| format!("Failed to open {}: {}", path.display(), e))?;
+
+    for entry in entries {
+        let line = serde_json::to_string(entry).expect("Entries are valid JSON");
+
+        writeln!(file, "{}", line)
+            .map_err(|e| format!("Failed to write {}: {}", path.display(), e))?;
+    }
+
+    Ok(())
+}
+
+pub fn load(path: &Path) -> Result<Vec<Entry>, String> {
+    if !path.exists() {
+        return Err(format!(
+            "There is no benchmark history in {} yet, run `bench` first",
+            path.display()
+        ));
+    }
+
+    let content = fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+
+    content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            serde_json::from_str(line).map_err(|e| {
+                format!(
+                    "Line {} of {} is not a valid entry: {}",
+                    i + 1,
+                    path.display(),
+                    e
+                )
+            })
+        })
+        .collect()
+}
+
+/// Resolves a commit, or a prefix of one, to the most recently benchmarked commit it matches.
+pub fn find_commit(entries: &[Entry], machine: &str, commit: &str) -> Option<String> {
+    entries
+        .iter()
+        .rev()
+        .filter(|e| e.machine == machine)
+        .find(|e| e.commit.starts_with(commit))
+        .map(|e| e.commit.clone())
+}
+
+pub fn last_commit(entries: &[Entry], machine: &str) -> Option<String> {
+    find_commit(entries, machine, "")
+}
+
+/// The most recently benchmarked commit other than `target`.
+pub fn previous_commit(entries: &[Entry], machine: &str, target: &str) -> Option<String> {
+    entries
+        .iter()
+        .rev()
+        .filter(|e| e.machine == machine && e.commit != target)
+        .map(|e| e.commit.clone())
+        .next()
+}
+
+pub struct Comparison {
+    pub day: usize,
+    pub step: Step,
+    pub base: Duration,
+    pub target: Duration,
+}
+
+impl Comparison {
+    /// Relative change of the median, `0.25` means the target is 25% slower than the base.
+    pub fn change(&self) -> f64 {
+        self.target.as_secs_f64() / self.base.as_secs_f64() - 1.0
+    }
+
+    pub fn regressed(&self, threshold: f64) -> bool {
+        self.change() > threshold
+    }
+}
+
+/// Compares the latest medians of every step of a year that was benchmarked on both commits.
+pub fn compare(
+    entries: &[Entry],
+    machine: &str,
+    year: u16,
+    base: &str,
+    target: &str,
+) -> Vec<Comparison> {
+    let base = latest_medians(entries, machine, year, base);
+    let target = latest_medians(entries, machine, year, target);
+
+    base.into_iter()
+        .filter_map(|((day, step), base)| {
+            target.get(&(day, step)).map(|target| Comparison {
+                day,
+                step,
+                base,
+                target: *target,
+            })
+        })
+        .collect()
+}
+
+fn latest_medians(
+    entries: &[Entry],
+    machine: &str,
+    year: u16,
+    commit: &str,
+) -> BTreeMap<(usize, Step), Duration> {
+    entries
+        .iter()
+        .filter(|e| e.machine == machine && e.year == year && e.commit == commit)
+        .map(|e| ((e.day, e.step), Duration::from_nanos(e.median_ns)))
+        .collect()
+}
+
+pub fn print_comparisons(comparisons: &[Comparison], threshold: f64) {
+    println!(
+        "{:<4} {:<7} {:>12} {:>12} {:>9}",
+        "Day", "Step", "Base", "Target", "Change"
+    );
+
+    for comparison in comparisons {
+        println!(
+            "{:<4} {:<7} {:>12} {:>12} {:>+8.1}%{}",
+            comparison.day,
+            comparison.step.to_string(),
+            utils::format_duration(comparison.base),
+            utils::format_duration(comparison.target),
+            comparison.change() * 100.0,
+            if comparison.regressed(threshold) {
+                "  regressed"
+            } else {
+                ""
+            }
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::Part;
+
+    fn entry(commit: &str, day: usize, step: Step, median_ns: u64) -> Entry {
+        Entry {
+            commit: commit.to_string(),
+            machine: "laptop".to_string(),
+            timestamp: 0,
+            year: 2024,
+            day,
+            step,
+            runs: 10,
+            min_ns: median_ns,
+            median_ns,
+            mean_ns: median_ns,
+            stddev_ns: 0,
+        }
+    }
+
+    #[test]
+    pub fn test_compare() {
+        let entries = vec![
+            entry("aaa", 6, Step::Part(Part::Two), 1000),
+            entry("aaa", 9, Step::Parse, 1000),
+            entry("bbb", 6, Step::Part(Part::Two), 1500),
+            entry("bbb", 6, Step::Part(Part::Two), 1200),
+            entry("bbb", 9, Step::Parse, 1050),
+            entry("bbb", 12, Step::Parse, 1050),
+        ];
+
+        assert_eq!(last_commit(&entries, "laptop"), Some("bbb".to_string()));
+        assert_eq!(
+            previous_commit(&entries, "laptop", "bbb"),
+            Some("aaa".to_string())
+        );
+        assert_eq!(last_commit(&entries, "desktop"), None);
+
+        let comparisons = compare(&entries, "laptop", 2024, "aaa", "bbb");
+
+        assert_eq!(comparisons.len(), 2);
+        assert_eq!(comparisons[0].target, Duration::from_nanos(1200));
+        assert!(comparisons[0].regressed(0.1));
+        assert!(!comparisons[1].regressed(0.1));
+    }
+
+    #[test]
+    pub fn test_append_and_load() {
+        let path = std::env::temp_dir()
+            .join(format!("aoc-history-{}", std::process::id()))
+            .join("bench-history");
+        let entries = vec![
+            entry("aaa", 1, Step::Parse, 5),
+            entry("aaa", 1, Step::Part(Part::One), 7),
+        ];
+
+        append(&path, &entries[..1]).unwrap();
+        append(&path, &entries[1..]).unwrap();
+
+        assert_eq!(load(&path), Ok(entries));
+
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+}
@@ -0,0 +1,86 @@
+//! A tiny HTTP server for tests that talk to adventofcode.com through a configurable base URL.
+
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpListener;
+use tokio::task::JoinHandle;
+
+#[derive(Debug)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl Request {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// Answers one connection per response, in order. Returns the base URL of the server and a
+/// handle that resolves to the requests it received once every response has been sent.
+pub async fn serve(responses: Vec<(u16, &'static str)>) -> (String, JoinHandle<Vec<Request>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+    let handle = tokio::spawn(async move {
+        let mut requests = vec![];
+
+        for (status, body) in responses {
+            let (stream, _) = listener.accept().await.unwrap();
+            let (read, mut write) = stream.into_split();
+            let mut reader = BufReader::new(read);
+
+            let mut line = String::new();
+            reader.read_line(&mut line).await.unwrap();
+            let mut request_line = line.split_whitespace();
+            let method = request_line.next().unwrap().to_string();
+            let path = request_line.next().unwrap().to_string();
+
+            let mut headers = vec![];
+            loop {
+                line.clear();
+                reader.read_line(&mut line).await.unwrap();
+
+                match line.trim_end().split_once(": ") {
+                    Some((key, value)) => headers.push((key.to_string(), value.to_string())),
+                    None => break,
+                }
+            }
+
+            let mut request = Request {
+                method,
+                path,
+                headers,
+                body: String::new(),
+            };
+
+            let length: usize = request
+                .header("content-length")
+                .map(|l| l.parse().unwrap())
+                .unwrap_or(0);
+            let mut content = vec![0; length];
+            reader.read_exact(&mut content).await.unwrap();
+            request.body = String::from_utf8(content).unwrap();
+
+            let response = format!(
+                "HTTP/1.1 {} Mock\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            );
+            write.write_all(response.as_bytes()).await.unwrap();
+            write.shutdown().await.unwrap();
+
+            requests.push(request);
+        }
+
+        requests
+    });
+
+    (base_url, handle)
+}
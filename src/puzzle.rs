@@ -0,0 +1,132 @@
+use crate::answers::Answers;
+use crate::solution::Part;
+use crate::utils;
+use lazy_static::lazy_static;
+use regex::Regex;
+
+lazy_static! {
+    static ref ARTICLE: Regex =
+        Regex::new(r#"(?s)<article class="day-desc">(.*?)</article>"#).unwrap();
+    static ref PRE_CODE: Regex = Regex::new(r"(?s)<pre><code>(.*?)</code></pre>").unwrap();
+    static ref EMPHASIZED_CODE: Regex = Regex::new(r"(?s)<code><em>(.*?)</em></code>").unwrap();
+    static ref TAG: Regex = Regex::new(r"<[^>]*>").unwrap();
+}
+
+pub fn path(year: u16, day: usize) -> String {
+    format!("{}/puzzle.html", utils::day_dir(year, day))
+}
+
+/// The examples of a puzzle page. The page only has part 2 once part 1 is solved, and
+/// `part2` is only set when part 2 brings an example of its own.
+#[derive(Debug, Default, PartialEq)]
+pub struct Examples {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+    pub answers: Answers,
+}
+
+/// Finds the example input and answer of every part. The input is the first code block after a
+/// paragraph mentioning an example, or the first code block when none does. The answer is the
+/// last emphasized code of the part, which is how the puzzles highlight the example's result.
+pub fn extract_examples(html: &str) -> Examples {
+    let mut examples = Examples::default();
+
+    for (part, article) in [Part::One, Part::Two]
+        .into_iter()
+        .zip(ARTICLE.captures_iter(html))
+    {
+        let article = article.get(1).unwrap().as_str();
+
+        let input = example_input(article);
+        let answer = EMPHASIZED_CODE
+            .captures_iter(article)
+            .last()
+            .map(|c| decode(&c[1]));
+
+        if let Some(answer) = answer {
+            examples.answers.set(part, &answer);
+        }
+
+        match part {
+            Part::One => examples.part1 = input,
+            Part::Two => examples.part2 = input.filter(|i| Some(i) != examples.part1.as_ref()),
+        }
+    }
+
+    examples
+}
+
+fn example_input(article: &str) -> Option<String> {
+    let mut text_start = 0;
+    let mut first = None;
+
+    for block in PRE_CODE.captures_iter(article) {
+        let whole = block.get(0).unwrap();
+        let code = block.get(1).unwrap().as_str();
+
+        if article[text_start..whole.start()]
+            .to_lowercase()
+            .contains("example")
+        {
+            return Some(decode(code));
+        }
+
+        first.get_or_insert(code);
+        text_start = whole.end();
+    }
+
+    first.map(decode)
+}
+
+/// Turns the HTML of a code block back into text, examples end without a line break like the
+/// existing test files do.
+fn decode(html: &str) -> String {
+    TAG.replace_all(html, "")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+        .trim_end_matches('\n')
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIXTURE: &str = include_str!("../inputs/fixtures/puzzle.html");
+
+    #[test]
+    pub fn test_extract_examples() {
+        let examples = extract_examples(FIXTURE);
+
+        assert_eq!(
+            examples.part1.as_deref(),
+            Some("3   4\n4   3\n2   5\n1   3\n3   9\n3   3")
+        );
+        assert_eq!(examples.part2.as_deref(), Some("1   2\n2   1\n<3>   3"));
+        assert_eq!(examples.answers.get(Part::One), Some("11"));
+        assert_eq!(examples.answers.get(Part::Two), Some("31"));
+    }
+
+    #[test]
+    pub fn test_extract_examples_before_part_two() {
+        let part1 = &FIXTURE[..FIXTURE.find("<p>Your puzzle answer").unwrap()];
+        let examples = extract_examples(part1);
+
+        assert!(examples.part1.is_some());
+        assert_eq!(examples.part2, None);
+        assert_eq!(examples.answers.get(Part::Two), None);
+    }
+
+    #[test]
+    pub fn test_part_two_reusing_the_example() {
+        let html = "<article class=\"day-desc\"><p>For example:</p><pre><code>1\n2\n</code></pre></article>\
+            <article class=\"day-desc\"><p>Same example again:</p><pre><code>1\n2\n</code></pre></article>";
+        let examples = extract_examples(html);
+
+        assert_eq!(examples.part1.as_deref(), Some("1\n2"));
+        assert_eq!(examples.part2, None);
+    }
+}
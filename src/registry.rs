@@ -0,0 +1,32 @@
+use crate::solution::Runnable;
+use crate::year_2024;
+
+pub const DAY_COUNT: usize = 25;
+
+pub struct Year {
+    pub year: u16,
+    pub days: &'static [Option<&'static dyn Runnable>; DAY_COUNT],
+}
+
+/// Every event with solutions, oldest first.
+pub const YEARS: &[Year] = &[Year {
+    year: 2024,
+    days: &year_2024::DAYS,
+}];
+
+/// The year that is used when `--year` is not given.
+pub fn latest_year() -> u16 {
+    YEARS.last().expect("At least one year is registered").year
+}
+
+/// Returns the solution for the given day, or `None` when the day is not implemented (yet).
+pub fn get(year: u16, day: usize) -> Option<&'static dyn Runnable> {
+    if day == 0 || day > DAY_COUNT {
+        return None;
+    }
+
+    YEARS
+        .iter()
+        .find(|y| y.year == year)
+        .and_then(|y| y.days[day - 1])
+}
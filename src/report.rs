@@ -0,0 +1,536 @@
+use crate::answers::Verdict;
+use crate::context::Log;
+use crate::solution::{Answer, Part};
+use crate::timing::{Phase, Timing};
+use crate::utils;
+use crate::utils::parse::ParseError;
+use clap::ValueEnum;
+use serde::Serialize;
+use std::fmt::{Display, Formatter};
+use std::time::Duration;
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, ValueEnum)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    Ok,
+    NotImplemented,
+    ParseError,
+    Panicked,
+    TimedOut,
+}
+
+impl Status {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Status::Ok => "ok",
+            Status::NotImplemented => "not_implemented",
+            Status::ParseError => "parse_error",
+            Status::Panicked => "panicked",
+            Status::TimedOut => "timed_out",
+        }
+    }
+
+    fn label(&self) -> String {
+        self.as_str().replace('_', " ")
+    }
+}
+
+/// Why a day did not finish, the parts that did not run get the matching status.
+#[derive(Clone, Debug, PartialEq)]
+pub enum DayError {
+    Parse(ParseError),
+    Panicked(String),
+    TimedOut(Duration),
+}
+
+impl DayError {
+    pub fn status(&self) -> Status {
+        match self {
+            DayError::Parse(_) => Status::ParseError,
+            DayError::Panicked(_) => Status::Panicked,
+            DayError::TimedOut(_) => Status::TimedOut,
+        }
+    }
+}
+
+impl Display for DayError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DayError::Parse(error) => write!(f, "{}", error),
+            DayError::Panicked(message) => write!(f, "panicked: {}", message),
+            DayError::TimedOut(timeout) => {
+                write!(f, "timed out after {}s", timeout.as_secs_f64())
+            }
+        }
+    }
+}
+
+pub struct DayReport {
+    pub year: u16,
+    pub day: usize,
+    pub parse: Timing,
+    pub parts: Vec<PartReport>,
+    /// Set when the day did not finish, the parts that did not run have the matching status.
+    pub error: Option<DayError>,
+    /// What the solution logged, in the order it was logged.
+    pub logs: Vec<Log>,
+}
+
+pub struct PartReport {
+    pub part: Part,
+    pub status: Status,
+    pub answer: Option<Answer>,
+    /// How the answer compares to the answers file, `expected` is only set when it is known.
+    pub verdict: Verdict,
+    pub expected: Option<String>,
+    pub solve: Timing,
+}
+
+impl PartReport {
+    /// A part that was not solved, without an answer to check.
+    pub fn skipped(part: Part, status: Status) -> PartReport {
+        PartReport {
+            part,
+            status,
+            answer: None,
+            verdict: Verdict::Unknown,
+            expected: None,
+            solve: Timing::default(),
+        }
+    }
+}
+
+impl DayReport {
+    pub fn total_time(&self) -> Duration {
+        self.parse.total + self.parts.iter().map(|p| p.solve.total).sum::<Duration>()
+    }
+}
+
+/// A single line in the JSON or CSV output, times are in nanoseconds. The phases are only
+/// written in the JSON output.
+#[derive(Serialize)]
+struct Record {
+    year: u16,
+    day: usize,
+    part: Part,
+    status: Status,
+    answer: Option<Answer>,
+    verdict: Verdict,
+    expected: Option<String>,
+    parse_ns: u128,
+    solve_ns: u128,
+    parse_phases: Vec<Phase>,
+    solve_phases: Vec<Phase>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+/// Writes the day reports to stdout in the selected format. Text and CSV are written as soon as
+/// a day is done, JSON is written as a single array once every day has been reported. The text
+/// output ends with a summary table when more than one day was selected. The logs of the solutions
+/// go to stdout with the text output and to stderr with the others, so those stay parseable.
+pub struct Reporter {
+    format: Format,
+    /// Only write the answers and the errors in the text output.
+    quiet: bool,
+    json: Vec<Record>,
+    summary: Vec<SummaryRow>,
+}
+
+/// A line of the summary table, days without a solution have no parts.
+struct SummaryRow {
+    day: usize,
+    status: String,
+    parts: [Option<Status>; 2],
+    time: Option<Duration>,
+}
+
+impl SummaryRow {
+    fn new(report: &DayReport) -> SummaryRow {
+        let part = |part: Part| {
+            report
+                .parts
+                .iter()
+                .find(|p| p.part == part)
+                .map(|p| p.status)
+        };
+        let status = match &report.error {
+            Some(error) => error.status(),
+            None if report
+                .parts
+                .iter()
+                .all(|p| p.status == Status::NotImplemented) =>
+            {
+                Status::NotImplemented
+            }
+            None => Status::Ok,
+        };
+
+        SummaryRow {
+            day: report.day,
+            status: status.label(),
+            parts: [part(Part::One), part(Part::Two)],
+            time: Some(report.total_time()),
+        }
+    }
+}
+
+impl Reporter {
+    pub fn new(format: Format, quiet: bool) -> Reporter {
+        if format == Format::Csv {
+            println!("year,day,part,status,answer,verdict,expected,parse_ns,solve_ns");
+        }
+
+        Reporter {
+            format,
+            quiet,
+            json: vec![],
+            summary: vec![],
+        }
+    }
+
+    pub fn report(&mut self, report: &DayReport) {
+        self.summary.push(SummaryRow::new(report));
+
+        if self.format != Format::Text {
+            for log in &report.logs {
+                eprintln!("{}", log_line(report.day, log));
+            }
+        }
+
+        match self.format {
+            Format::Text if self.quiet => print_quiet(report),
+            Format::Text => print_text(report),
+            Format::Json => self.json.extend(records(report)),
+            Format::Csv => {
+                for record in records(report) {
+                    println!(
+                        "{},{},{},{},{},{},{},{},{}",
+                        record.year,
+                        record.day,
+                        record.part,
+                        record.status.as_str(),
+                        record.answer.as_ref().map(csv_field).unwrap_or_default(),
+                        record.verdict.as_str(),
+                        record
+                            .expected
+                            .as_ref()
+                            .map(|e| csv_field(&Answer::from(e.as_str())))
+                            .unwrap_or_default(),
+                        record.parse_ns,
+                        record.solve_ns
+                    );
+                }
+            }
+        }
+    }
+
+    /// Adds a selected day that has no solution to the summary.
+    pub fn not_implemented(&mut self, day: usize) {
+        self.summary.push(SummaryRow {
+            day,
+            status: Status::NotImplemented.label(),
+            parts: [None, None],
+            time: None,
+        });
+    }
+
+    /// Writes what is left of the output. `wall` is how long solving every day took from start
+    /// to end, the summary compares it to the time the days took together.
+    pub fn finish(mut self, wall: Duration) {
+        match self.format {
+            Format::Json => println!(
+                "{}",
+                serde_json::to_string_pretty(&self.json).expect("Records are valid JSON")
+            ),
+            Format::Text if self.summary.len() > 1 && !self.quiet => {
+                self.summary.sort_by_key(|row| row.day);
+                print!("{}", summary_table(&self.summary));
+
+                let combined: Duration = self.summary.iter().filter_map(|row| row.time).sum();
+                println!(
+                    "Solved in {} wall time, the days took {} together",
+                    utils::format_duration(wall),
+                    utils::format_duration(combined)
+                );
+            }
+            _ => {}
+        }
+    }
+}
+
+fn summary_table(rows: &[SummaryRow]) -> String {
+    let mut table = format!(
+        "{:>3}  {:<15}  {:<15}  {:<15}  {}\n",
+        "Day", "Status", "Part 1", "Part 2", "Time"
+    );
+
+    for row in rows {
+        let [one, two] = row
+            .parts
+            .map(|part| part.map_or("-".to_string(), |p| p.label()));
+        let time = row.time.map(utils::format_duration).unwrap_or_default();
+
+        table.push_str(
+            format!(
+                "{:>3}  {:<15}  {:<15}  {:<15}  {}",
+                row.day, row.status, one, two, time
+            )
+            .trim_end(),
+        );
+        table.push('\n');
+    }
+
+    table
+}
+
+fn records(report: &DayReport) -> impl Iterator<Item = Record> + '_ {
+    report.parts.iter().map(|part| Record {
+        year: report.year,
+        day: report.day,
+        part: part.part,
+        status: part.status,
+        answer: part.answer.clone(),
+        verdict: part.verdict,
+        expected: part.expected.clone(),
+        parse_ns: report.parse.total.as_nanos(),
+        solve_ns: part.solve.total.as_nanos(),
+        parse_phases: report.parse.phases.clone(),
+        solve_phases: part.solve.phases.clone(),
+        error: report.error.as_ref().map(DayError::to_string),
+    })
+}
+
+fn print_text(report: &DayReport) {
+    utils::print_day_banner(report.day);
+
+    println!(
+        "Parsing took {}",
+        utils::format_duration(report.parse.total)
+    );
+    print_phases(&report.parse.phases);
+    print_logs(&report.logs, None);
+
+    match &report.error {
+        Some(DayError::Parse(error)) => println!("Failed to parse the input, {}", error),
+        Some(error) => println!("Day {} {}", report.day, error),
+        None => {}
+    }
+
+    for part in report
+        .parts
+        .iter()
+        .filter(|p| p.status != Status::ParseError)
+    {
+        match &part.answer {
+            Some(answer) => println!(
+                "Part {}: {} ({}) [{}]",
+                part.part,
+                answer,
+                utils::format_duration(part.solve.total),
+                match (&part.verdict, &part.expected) {
+                    (Verdict::Fail, Some(expected)) => format!("fail, expected {}", expected),
+                    (verdict, _) => verdict.as_str().to_string(),
+                }
+            ),
+            None => println!("Part {}: {}", part.part, part.status.label()),
+        }
+
+        print_phases(&part.solve.phases);
+        print_logs(&report.logs, Some(part.part));
+    }
+
+    utils::print_duration(report.total_time(), report.day);
+}
+
+fn print_quiet(report: &DayReport) {
+    if let Some(error) = &report.error {
+        println!("Day {} {}", report.day, error);
+    }
+
+    for part in &report.parts {
+        if let Some(answer) = &part.answer {
+            println!("Day {} part {}: {}", report.day, part.part, answer);
+        }
+    }
+}
+
+/// Prints the logs of a part, or the ones from before the first part, under its line.
+fn print_logs(logs: &[Log], part: Option<Part>) {
+    for log in logs.iter().filter(|log| log.part == part) {
+        for line in log.message.lines() {
+            println!(
+                "{}",
+                format!("    [{}] {}", log.level.as_str(), line).trim_end()
+            );
+        }
+    }
+}
+
+fn log_line(day: usize, log: &Log) -> String {
+    let part = log
+        .part
+        .map(|part| format!(" part {}", part))
+        .unwrap_or_default();
+
+    format!(
+        "Day {}{} [{}] {}",
+        day,
+        part,
+        log.level.as_str(),
+        log.message
+    )
+}
+
+fn print_phases(phases: &[Phase]) {
+    for phase in phases {
+        println!(
+            "    {}: {}",
+            phase.name,
+            utils::format_duration(phase.duration)
+        );
+    }
+}
+
+/// Quotes answers like day 17's `4,6,3,5` so they stay a single CSV field.
+fn csv_field(answer: &Answer) -> String {
+    let value = answer.to_string();
+
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::context::Level;
+
+    fn report() -> DayReport {
+        DayReport {
+            year: 2024,
+            day: 17,
+            parse: Timing {
+                total: Duration::from_nanos(100),
+                phases: vec![],
+            },
+            parts: vec![
+                PartReport {
+                    part: Part::One,
+                    status: Status::Ok,
+                    answer: Some(Answer::from("4,6,3")),
+                    verdict: Verdict::Fail,
+                    expected: Some("4,6,2".to_string()),
+                    solve: Timing {
+                        total: Duration::from_nanos(250),
+                        phases: vec![Phase {
+                            name: "run",
+                            duration: Duration::from_nanos(200),
+                        }],
+                    },
+                },
+                PartReport::skipped(Part::Two, Status::NotImplemented),
+            ],
+            error: None,
+            logs: vec![],
+        }
+    }
+
+    #[test]
+    pub fn test_json_records() {
+        let json: Vec<serde_json::Value> = records(&report())
+            .map(|r| serde_json::to_value(r).unwrap())
+            .collect();
+
+        assert_eq!(
+            json[0],
+            serde_json::json!({
+                "year": 2024, "day": 17, "part": 1, "status": "ok", "answer": "4,6,3",
+                "verdict": "fail", "expected": "4,6,2", "parse_ns": 100, "solve_ns": 250,
+                "parse_phases": [], "solve_phases": [{"name": "run", "ns": 200}]
+            })
+        );
+        assert_eq!(json[1]["status"], "not_implemented");
+        assert_eq!(json[1]["answer"], serde_json::Value::Null);
+        assert_eq!(json[1]["verdict"], "unknown");
+        assert_eq!(serde_json::to_value(Answer::from(42usize)).unwrap(), 42);
+    }
+
+    #[test]
+    pub fn test_parse_error_records() {
+        let report = DayReport {
+            parts: vec![PartReport::skipped(Part::One, Status::ParseError)],
+            error: Some(DayError::Parse(
+                ParseError::new(3, 7, "expected a number, found `x`").in_day(17),
+            )),
+            ..report()
+        };
+        let json = serde_json::to_value(records(&report).next().unwrap()).unwrap();
+
+        assert_eq!(json["status"], "parse_error");
+        assert_eq!(
+            json["error"],
+            "day 17, line 3, column 7: expected a number, found `x`"
+        );
+    }
+
+    #[test]
+    pub fn test_summary_table() {
+        let timed_out = DayReport {
+            day: 14,
+            parts: vec![
+                PartReport::skipped(Part::One, Status::Ok),
+                PartReport::skipped(Part::Two, Status::TimedOut),
+            ],
+            error: Some(DayError::TimedOut(Duration::from_secs(10))),
+            ..report()
+        };
+        let mut rows = vec![SummaryRow::new(&report()), SummaryRow::new(&timed_out)];
+        rows.push(SummaryRow {
+            day: 21,
+            status: Status::NotImplemented.label(),
+            parts: [None, None],
+            time: None,
+        });
+
+        assert_eq!(
+            summary_table(&rows),
+            "Day  Status           Part 1           Part 2           Time\n \
+              17  ok               ok               not implemented  0.000ms\n \
+              14  timed out        ok               timed out        0.000ms\n \
+              21  not implemented  -                -\n"
+        );
+    }
+
+    #[test]
+    pub fn test_csv_field() {
+        assert_eq!(csv_field(&Answer::from(29328usize)), "29328");
+        assert_eq!(csv_field(&Answer::from("4,6,3")), "\"4,6,3\"");
+        assert_eq!(csv_field(&Answer::from("say \"hi\"")), "\"say \"\"hi\"\"\"");
+    }
+
+    #[test]
+    pub fn test_log_line() {
+        let log = Log {
+            part: Some(Part::Two),
+            level: Level::Debug,
+            message: "3 robots left".to_string(),
+        };
+
+        assert_eq!(log_line(14, &log), "Day 14 part 2 [debug] 3 robots left");
+        assert_eq!(
+            log_line(14, &Log { part: None, ..log }),
+            "Day 14 [debug] 3 robots left"
+        );
+    }
+}
@@ -0,0 +1,931 @@
+use crate::answers::{self, Answers, Verdict};
+use crate::bench::{self, BenchReport, Stats, Step};
+use crate::cli::{
+    AssembleArgs, BenchArgs, CompareArgs, Days, DownloadArgs, InputSource, NewArgs, RunArgs,
+    SubmitArgs, TestArgs,
+};
+use crate::client::{Client, ClientError};
+use crate::context::{Context, Level};
+use crate::history;
+use crate::puzzle;
+use crate::registry;
+use crate::report::{DayError, DayReport, PartReport, Reporter, Status};
+use crate::scaffold;
+use crate::solution::{Answer, Part, Runnable};
+use crate::submit::{self, Attempt, Outcome};
+use crate::timing::{self, Timing};
+use crate::utils;
+use crate::utils::parse::ParseError;
+use crate::visualizer::Visualizer;
+use crate::year_2024::day_17;
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+
+/// How many missing inputs are downloaded at the same time with `--jobs`, to go easy on the site.
+const MAX_DOWNLOADS: usize = 4;
+
+pub async fn run(year: u16, args: RunArgs) -> Result<(), String> {
+    let selection = args.selection;
+    let requested = selection.days.clone().unwrap_or_else(Days::all);
+    let days = select_days(year, selection.days, selection.input.is_some())?;
+    let selected: Vec<usize> = days.iter().map(|(day, _)| *day).collect();
+    let inputs = load_inputs(
+        year,
+        &selected,
+        selection.input.as_ref(),
+        args.jobs.min(MAX_DOWNLOADS),
+    )
+    .await?;
+
+    let mut reporter = Reporter::new(args.format, args.quiet);
+    let mut failed = 0;
+    let mut unfinished = 0;
+
+    for day in requested.0 {
+        if registry::get(year, day).is_none() {
+            reporter.not_implemented(day);
+        }
+    }
+
+    // The stored answers belong to the real input, answers for other inputs stay unknown
+    let mut answers = selected
+        .iter()
+        .map(|day| match selection.input {
+            Some(_) => Ok(Answers::default()),
+            None => Answers::load(year, *day),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let options = DayOptions {
+        part: selection.part,
+        timeout: args.timeout,
+        log_level: if args.quiet {
+            Level::Off
+        } else {
+            args.log_level
+        },
+        visualizer: args
+            .visualize
+            .then(|| Visualizer::new(args.fps))
+            .transpose()?,
+        breakpoints: args.breakpoints,
+    };
+    let started = Instant::now();
+    let mut reports: Vec<DayReport> = vec![];
+
+    let solve = |index: usize| {
+        let (day, solution) = days[index];
+        let input = inputs[index].clone();
+
+        run_day(year, day, solution, input, &answers[index], &options)
+    };
+    let done = |report: DayReport| {
+        reporter.report(&report);
+        reports.push(report);
+    };
+
+    // The frames have the terminal to themselves, a day only starts once the last one is reported
+    match options.visualizer {
+        Some(_) => (0..days.len()).map(solve).for_each(done),
+        None => solve_days(args.jobs, days.len(), solve, done),
+    }
+
+    let wall = started.elapsed();
+
+    for (report, answers) in reports.iter().zip(answers.iter_mut()) {
+        // The other days still run, the error is only reported at the end
+        if report.error.is_some() {
+            unfinished += 1;
+        }
+
+        failed += report
+            .parts
+            .iter()
+            .filter(|part| part.verdict == Verdict::Fail)
+            .count();
+
+        if args.save_answers {
+            let mut changed = false;
+
+            for part in &report.parts {
+                if let (Some(answer), Verdict::Unknown) = (&part.answer, part.verdict) {
+                    answers.set(part.part, &answer.to_string());
+                    changed = true;
+                }
+            }
+
+            if changed {
+                answers.save(year, report.day)?;
+            }
+        }
+    }
+
+    reporter.finish(wall);
+
+    if unfinished > 0 {
+        return Err(format!(
+            "{} day(s) could not be parsed, panicked or timed out",
+            unfinished
+        ));
+    }
+
+    if failed > 0 {
+        return Err(format!(
+            "{} answer(s) did not match the answers file",
+            failed
+        ));
+    }
+
+    Ok(())
+}
+
+/// Runs `solve` for every index below `count` on `jobs` threads and hands the results to `done`
+/// in the order of the indices, each one as soon as the ones before it are done.
+fn solve_days<T: Send>(
+    jobs: usize,
+    count: usize,
+    solve: impl Fn(usize) -> T + Sync,
+    mut done: impl FnMut(T),
+) {
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(count) {
+            let sender = sender.clone();
+            let (next, solve) = (&next, &solve);
+
+            scope.spawn(move || loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                if index >= count {
+                    break;
+                }
+
+                let _ = sender.send((index, solve(index)));
+            });
+        }
+
+        drop(sender);
+
+        let mut finished = BTreeMap::new();
+        let mut reported = 0;
+
+        for (index, result) in receiver {
+            finished.insert(index, result);
+
+            while let Some(result) = finished.remove(&reported) {
+                done(result);
+                reported += 1;
+            }
+        }
+    });
+}
+
+/// What the thread solving a day has finished so far.
+enum Progress {
+    Parsed(Timing, Option<ParseError>),
+    Solved(Part, Answer, Timing),
+}
+
+/// How every day of a run is solved.
+struct DayOptions {
+    part: Option<Part>,
+    timeout: Option<Duration>,
+    log_level: Level,
+    visualizer: Option<Arc<Visualizer>>,
+    breakpoints: Vec<String>,
+}
+
+/// Solves a day on its own thread, so a panic only ends that day and a day that runs longer than
+/// the timeout is given up on. The thread of a day that timed out cannot be stopped, it keeps
+/// running in the background until the process exits.
+fn run_day(
+    year: u16,
+    day: usize,
+    solution: &'static dyn Runnable,
+    input: String,
+    answers: &Answers,
+    options: &DayOptions,
+) -> DayReport {
+    let timeout = options.timeout;
+    let selected = options
+        .part
+        .map(|part| vec![part])
+        .unwrap_or_else(|| solution.parts().to_vec());
+    let sink = options.visualizer.as_ref().map(|visualizer| {
+        visualizer.start(day);
+        visualizer.screen(day)
+    });
+    let context = Arc::new(
+        Context::new(options.log_level, sink).with_breakpoints(options.breakpoints.clone()),
+    );
+    let (sender, receiver) = mpsc::channel();
+
+    let worker = {
+        let selected = selected.clone();
+        let context = Arc::clone(&context);
+
+        thread::Builder::new()
+            .name(format!("day {}", day))
+            .spawn(move || solve_day(solution, &input, &selected, &context, &sender))
+            .expect("Failed to start the thread of a day")
+    };
+
+    let deadline = timeout.map(|timeout| Instant::now() + timeout);
+    let mut step_started = Instant::now();
+    let mut parsed = false;
+    let mut report = DayReport {
+        year,
+        day,
+        parse: Timing::default(),
+        parts: vec![],
+        error: None,
+        logs: vec![],
+    };
+
+    let error = loop {
+        let progress = match deadline {
+            Some(deadline) => {
+                receiver.recv_timeout(deadline.saturating_duration_since(Instant::now()))
+            }
+            None => receiver.recv().map_err(|_| RecvTimeoutError::Disconnected),
+        };
+
+        if progress.is_ok() {
+            step_started = Instant::now();
+        }
+
+        match progress {
+            Ok(Progress::Parsed(parse, error)) => {
+                report.parse = parse;
+                parsed = true;
+
+                if let Some(error) = error {
+                    break Some(DayError::Parse(error.in_day(day)));
+                }
+            }
+            Ok(Progress::Solved(part, answer, solve)) => report.parts.push(PartReport {
+                part,
+                status: Status::Ok,
+                verdict: answers.check(part, &answer),
+                expected: answers.get(part).map(str::to_string),
+                answer: Some(answer),
+                solve,
+            }),
+            // The thread is done, either because every part is solved or because it panicked
+            Err(RecvTimeoutError::Disconnected) => {
+                break worker
+                    .join()
+                    .err()
+                    .map(|payload| DayError::Panicked(panic_message(payload)))
+            }
+            Err(RecvTimeoutError::Timeout) => {
+                break Some(DayError::TimedOut(timeout.unwrap_or_default()))
+            }
+        }
+    };
+
+    // The step that was running when the day panicked or timed out gets the time it ran for
+    let mut stalled = Some(step_started.elapsed()).filter(|_| error.is_some());
+    if !parsed {
+        report.parse.total = stalled.take().unwrap_or_default();
+    }
+
+    for part in selected {
+        if report.parts.iter().any(|p| p.part == part) {
+            continue;
+        }
+
+        let mut skipped = match &error {
+            _ if !solution.parts().contains(&part) => {
+                PartReport::skipped(part, Status::NotImplemented)
+            }
+            Some(error) => PartReport::skipped(part, error.status()),
+            None => unreachable!("Every part of a finished day is reported"),
+        };
+        skipped.solve.total = stalled.take().unwrap_or_default();
+
+        report.parts.push(skipped);
+    }
+
+    if let Some(visualizer) = &options.visualizer {
+        visualizer.finish();
+    }
+
+    report.error = error;
+    report.logs = context.take_logs();
+    report
+}
+
+/// Parses the input once and solves the requested parts, timing every step on its own. Every
+/// finished step is sent right away, so the steps before a panic or timeout are still reported.
+fn solve_day(
+    solution: &dyn Runnable,
+    input: &str,
+    parts: &[Part],
+    context: &Context,
+    progress: &Sender<Progress>,
+) {
+    let (parsed, parse) = timing::measure(|| solution.parse(input));
+
+    // Sending only fails when the day was given up on, nobody is waiting for the rest then
+    let parsed = match parsed {
+        Ok(parsed) => {
+            let _ = progress.send(Progress::Parsed(parse, None));
+
+            parsed
+        }
+        Err(error) => {
+            let _ = progress.send(Progress::Parsed(parse, Some(error)));
+
+            return;
+        }
+    };
+
+    for &part in parts {
+        if !solution.parts().contains(&part) {
+            continue;
+        }
+
+        context.start(part);
+
+        let (answer, solve) = timing::measure(|| solution.solve(parsed.as_ref(), part, context));
+        let _ = progress.send(Progress::Solved(part, answer, solve));
+    }
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|message| message.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "no message".to_string())
+}
+
+/// Times parsing and every selected part on its own. The parts reuse a single parsed input, so
+/// their numbers do not include parsing.
+pub async fn bench(year: u16, args: BenchArgs) -> Result<(), String> {
+    let selection = args.selection;
+    let days = select_days(year, selection.days, selection.input.is_some())?;
+    let config = bench::Config {
+        runs: args.runs,
+        budget: args.time,
+        warmup: args.warmup,
+    };
+    let mut reports: Vec<BenchReport> = vec![];
+
+    for (day, solution) in days {
+        let parts = select_parts(day, solution, selection.part);
+        if parts.is_empty() {
+            continue;
+        }
+
+        let input = load_input(year, day, selection.input.as_ref(), None).await?;
+
+        let parsed = match solution.parse(&input) {
+            Ok(parsed) => parsed,
+            Err(error) => {
+                eprintln!("Skipping day {}: {}", day, error.in_day(day));
+                continue;
+            }
+        };
+
+        let samples = bench::sample(&config, || solution.parse(&input));
+        reports.push(BenchReport {
+            year,
+            day,
+            step: Step::Parse,
+            stats: Stats::from_samples(&samples),
+        });
+
+        for part in parts {
+            let samples = bench::sample(&config, || {
+                solution.solve(parsed.as_ref(), part, &Context::silent())
+            });
+            reports.push(BenchReport {
+                year,
+                day,
+                step: Step::Part(part),
+                stats: Stats::from_samples(&samples),
+            });
+        }
+    }
+
+    bench::print_reports(&reports, args.format);
+
+    if !args.no_save && selection.input.is_none() {
+        let commit = history::current_commit();
+        let machine = history::current_machine();
+        let timestamp = history::now();
+        let entries: Vec<history::Entry> = reports
+            .iter()
+            .map(|report| history::Entry::new(report, &commit, &machine, timestamp))
+            .collect();
+
+        history::append(Path::new(history::PATH), &entries)?;
+
+        eprintln!("Saved to {} as {} on {}", history::PATH, commit, machine);
+    }
+
+    Ok(())
+}
+
+/// Compares two benchmarked commits and fails when any step regressed beyond the threshold.
+pub fn compare(year: u16, args: CompareArgs) -> Result<(), String> {
+    let entries = history::load(Path::new(history::PATH))?;
+    let machine = args.machine.unwrap_or_else(history::current_machine);
+
+    let target = match &args.target {
+        Some(commit) => history::find_commit(&entries, &machine, commit)
+            .ok_or_else(|| format!("Commit {} was not benchmarked on {}", commit, machine))?,
+        None => history::last_commit(&entries, &machine)
+            .ok_or_else(|| format!("Nothing was benchmarked on {}", machine))?,
+    };
+    let base = match &args.base {
+        Some(commit) => history::find_commit(&entries, &machine, commit)
+            .ok_or_else(|| format!("Commit {} was not benchmarked on {}", commit, machine))?,
+        None => history::previous_commit(&entries, &machine, &target).ok_or_else(|| {
+            format!(
+                "No other commit than {} was benchmarked on {}",
+                target, machine
+            )
+        })?,
+    };
+
+    let comparisons = history::compare(&entries, &machine, year, &base, &target);
+    if comparisons.is_empty() {
+        return Err(format!(
+            "{} and {} have no benchmarked steps in common",
+            base, target
+        ));
+    }
+
+    println!("Comparing {} to {} on {}", base, target, machine);
+    history::print_comparisons(&comparisons, args.threshold / 100.0);
+
+    let regressed = comparisons
+        .iter()
+        .filter(|c| c.regressed(args.threshold / 100.0))
+        .count();
+
+    if regressed > 0 {
+        return Err(format!(
+            "{} step(s) slowed down by more than {}%",
+            regressed, args.threshold
+        ));
+    }
+
+    Ok(())
+}
+
+/// Downloads the selected days one after the other. A day that fails is reported and skipped,
+/// unless the session is the problem, since every other day would fail the same way.
+pub async fn download(year: u16, args: DownloadArgs) -> Result<(), String> {
+    let mut client: Option<Client> = None;
+    let mut failed = 0;
+
+    for day in args.days.unwrap_or_else(Days::all).0 {
+        match download_day(&mut client, year, day, args.force, args.examples).await {
+            Ok(()) => {}
+            Err(e @ (ClientError::LoggedOut | ClientError::MissingSession)) => return Err(e.into()),
+            Err(e) => {
+                eprintln!("Failed to download day {}: {}", day, e);
+                failed += 1;
+
+                continue;
+            }
+        }
+
+        if args.examples {
+            match save_examples(year, day, args.force) {
+                Ok(written) if written.is_empty() => {}
+                Ok(written) => println!(
+                    "Extracted the examples of day {} into {}",
+                    day,
+                    written.join(", ")
+                ),
+                Err(e) => {
+                    eprintln!("Failed to extract the examples of day {}: {}", day, e);
+                    failed += 1;
+                }
+            }
+        }
+    }
+
+    if failed > 0 {
+        return Err(format!("{} download(s) failed", failed));
+    }
+
+    Ok(())
+}
+
+/// Downloads the input, and the puzzle page when the examples are wanted, unless they are
+/// already there. The client is only created once something has to be downloaded.
+async fn download_day(
+    client: &mut Option<Client>,
+    year: u16,
+    day: usize,
+    force: bool,
+    examples: bool,
+) -> Result<(), ClientError> {
+    let input = utils::input_path(year, day);
+    if force || !Path::new(&input).exists() {
+        if client.is_none() {
+            *client = Some(Client::from_env()?);
+        }
+
+        client
+            .as_ref()
+            .unwrap()
+            .download_input(year, day, Path::new(&input))
+            .await?;
+
+        println!("Downloaded the input for day {}", day);
+    }
+
+    let page = puzzle::path(year, day);
+    if examples && (force || !Path::new(&page).exists()) {
+        if client.is_none() {
+            *client = Some(Client::from_env()?);
+        }
+
+        client
+            .as_ref()
+            .unwrap()
+            .download_puzzle(year, day, Path::new(&page))
+            .await?;
+
+        println!("Downloaded the puzzle page for day {}", day);
+    }
+
+    Ok(())
+}
+
+/// Extracts the examples of the downloaded puzzle page into the test files of a day and returns
+/// the files it wrote. Files that already have content are kept unless `force` is set, since
+/// they are often edited by hand.
+fn save_examples(year: u16, day: usize, force: bool) -> Result<Vec<String>, String> {
+    let page = puzzle::path(year, day);
+    let html = fs::read_to_string(&page).map_err(|e| format!("Failed to read {}: {}", page, e))?;
+    let examples = puzzle::extract_examples(&html);
+    let mut written = vec![];
+
+    for (file, example) in [("test", examples.part1), ("p2_test", examples.part2)] {
+        let Some(example) = example else {
+            continue;
+        };
+
+        let path = format!("{}/{}", utils::day_dir(year, day), file);
+        let empty = fs::metadata(&path).map_or(true, |m| m.len() == 0);
+
+        if force || empty {
+            fs::write(&path, example).map_err(|e| format!("Failed to write {}: {}", path, e))?;
+            written.push(path);
+        }
+    }
+
+    let path = answers::example_path(year, day);
+    let mut answers = Answers::read(&path)?;
+    let mut changed = false;
+
+    for part in [Part::One, Part::Two] {
+        if let Some(answer) = examples.answers.get(part) {
+            if force || answers.get(part).is_none() {
+                answers.set(part, answer);
+                changed = true;
+            }
+        }
+    }
+
+    if changed {
+        answers.write(&path)?;
+        written.push(path);
+    }
+
+    Ok(written)
+}
+
+/// Submits an answer, unless the attempts made before already show it is wrong or a cooldown is
+/// still running. Correct answers are added to the answers file.
+pub async fn submit(year: u16, args: SubmitArgs) -> Result<(), String> {
+    let (day, part) = (args.day, args.part);
+
+    let answer = match args.answer {
+        Some(answer) => answer.trim().to_string(),
+        None => {
+            let (_, solution) = select_days(year, Some(Days(vec![day])), false)?[0];
+            if !solution.parts().contains(&part) {
+                return Err(format!("Day {} has no solution for part {}", day, part));
+            }
+
+            let input = load_input(year, day, None, None).await?;
+            let parsed = solution.parse(&input).map_err(|e| e.in_day(day))?;
+
+            solution
+                .solve(parsed.as_ref(), part, &Context::silent())
+                .to_string()
+        }
+    };
+
+    let path = Path::new(submit::PATH);
+    let attempts = submit::load(path)?;
+    submit::check(&attempts, year, day, part, &answer, history::now())?;
+
+    println!(
+        "Submitting {} for day {} part {} of {}",
+        answer, day, part, year
+    );
+
+    let client = Client::from_env()?;
+    let response = client.submit(year, day, part, &answer).await.map_err(|e| {
+        format!(
+            "{}. The answer may still have been counted, check the puzzle page before \
+                 submitting it again",
+            e
+        )
+    })?;
+    let outcome = submit::parse_response(&response);
+
+    submit::record(
+        path,
+        &Attempt {
+            year,
+            day,
+            part,
+            answer: answer.clone(),
+            timestamp: history::now(),
+            outcome: outcome.clone(),
+        },
+    )?;
+
+    if outcome != Outcome::Correct {
+        return Err(outcome.to_string());
+    }
+
+    println!("{}", outcome);
+
+    let mut answers = Answers::load(year, day)?;
+    answers.set(part, &answer);
+    answers.save(year, day)
+}
+
+/// Runs the example tests of the selected days through `cargo test`.
+pub fn test(year: u16, args: TestArgs) -> Result<(), String> {
+    let test_name = match args.part {
+        Some(Part::One) => "tests::test_part1",
+        Some(Part::Two) => "tests::test_part2",
+        None => "",
+    };
+
+    let filters: Vec<String> = args
+        .days
+        .unwrap_or_else(Days::all)
+        .0
+        .iter()
+        .map(|day| format!("year_{}::day_{}::{}", year, day, test_name))
+        .collect();
+
+    let status = process::Command::new("cargo")
+        .arg("test")
+        .arg("--")
+        .args(filters)
+        .status()
+        .map_err(|e| format!("Failed to start cargo test: {}", e))?;
+
+    if !status.success() {
+        return Err("Some tests failed".to_string());
+    }
+
+    Ok(())
+}
+
+pub async fn new_day(year: u16, args: NewArgs) -> Result<(), String> {
+    let day = args.day;
+    let module = scaffold::module_path(year, day);
+    let registry = scaffold::registry_path(year);
+
+    let source = fs::read_to_string(&registry).map_err(|e| {
+        format!(
+            "Failed to read {}, add year {} first: {}",
+            registry, year, e
+        )
+    })?;
+    if !args.force && Path::new(&module).exists() {
+        return Err(format!(
+            "{} already exists, use --force to replace it",
+            module
+        ));
+    }
+
+    create_input_dir(year, day)?;
+
+    let test_path = format!("{}/test", utils::day_dir(year, day));
+    if !Path::new(&test_path).exists() {
+        fs::write(&test_path, "").map_err(|e| format!("Failed to create {}: {}", test_path, e))?;
+    }
+
+    // A failed download leaves the day to be filled in by hand, it is no reason to not create it.
+    if args.download {
+        match download_day(&mut None, year, day, false, true).await {
+            Ok(()) => {
+                save_examples(year, day, false)?;
+            }
+            Err(e) => eprintln!("Failed to download day {}: {}", day, e),
+        }
+    }
+
+    let answers = Answers::read(&answers::example_path(year, day))?;
+    let p2_test = Path::new(&format!("{}/p2_test", utils::day_dir(year, day))).exists();
+
+    fs::write(&module, scaffold::render(year, day, &answers, p2_test))
+        .map_err(|e| format!("Failed to write {}: {}", module, e))?;
+    fs::write(&registry, scaffold::register(&source, day)?)
+        .map_err(|e| format!("Failed to write {}: {}", registry, e))?;
+
+    println!("Created {} and registered it in {}", module, registry);
+    if fs::metadata(&test_path).map_or(true, |m| m.len() == 0) {
+        println!("Paste the example input in {}", test_path);
+    }
+
+    Ok(())
+}
+
+/// Assembles day 17 source into an input, or disassembles an input back into source, and prints
+/// the result.
+pub fn assemble(args: AssembleArgs) -> Result<(), String> {
+    let text = match &args.file {
+        InputSource::Stdin => utils::read_stdin()?,
+        InputSource::File(path) => utils::read_input_file(&path.to_string_lossy())?,
+    };
+
+    let output = if args.disassemble {
+        day_17::disassemble_input(text.trim_end())?
+    } else {
+        day_17::assemble(&text)?
+    };
+
+    print!("{}", output);
+
+    Ok(())
+}
+
+fn create_input_dir(year: u16, day: usize) -> Result<(), String> {
+    let dir = utils::day_dir(year, day);
+
+    fs::create_dir_all(&dir).map_err(|e| format!("Failed to create {}: {}", dir, e))
+}
+
+/// Resolves the selected days to their solutions. Days that are not implemented are skipped,
+/// unless nothing would be left to run.
+fn select_days(
+    year: u16,
+    days: Option<Days>,
+    single_day_only: bool,
+) -> Result<Vec<(usize, &'static dyn Runnable)>, String> {
+    let explicit = days.is_some();
+    let days = days.unwrap_or_else(Days::all);
+
+    if single_day_only && (!explicit || days.0.len() != 1) {
+        return Err("--input can only be used when running a single day".to_string());
+    }
+
+    let selected: Vec<(usize, &dyn Runnable)> = days
+        .0
+        .iter()
+        .filter_map(|day| registry::get(year, *day).map(|solution| (*day, solution)))
+        .collect();
+
+    if selected.is_empty() {
+        return Err(match days.0.as_slice() {
+            [day] => format!("Day {} of {} is not implemented", day, year),
+            _ => format!("None of the selected days of {} are implemented", year),
+        });
+    }
+
+    Ok(selected)
+}
+
+fn select_parts(day: usize, solution: &dyn Runnable, part: Option<Part>) -> Vec<Part> {
+    match part {
+        None => solution.parts().to_vec(),
+        Some(part) if solution.parts().contains(&part) => vec![part],
+        Some(part) => {
+            eprintln!("Day {} has no solution for part {}, skipping", day, part);
+
+            vec![]
+        }
+    }
+}
+
+/// Loads the inputs of the days in their order, with up to `limit` of them downloading at once.
+async fn load_inputs(
+    year: u16,
+    days: &[usize],
+    source: Option<&InputSource>,
+    limit: usize,
+) -> Result<Vec<String>, String> {
+    let permits = Arc::new(Semaphore::new(limit));
+    let mut tasks = JoinSet::new();
+
+    // The downloads share one client, so its throttle spaces out all their requests. It is only
+    // needed when an input is missing, cached inputs load without a session.
+    let missing = source.is_none()
+        && days
+            .iter()
+            .any(|&day| !Path::new(&utils::input_path(year, day)).exists());
+    let client = if missing {
+        Some(Arc::new(Client::from_env()?))
+    } else {
+        None
+    };
+
+    for (index, &day) in days.iter().enumerate() {
+        let permits = Arc::clone(&permits);
+        let source = source.cloned();
+        let client = client.clone();
+
+        tasks.spawn(async move {
+            let _permit = permits.acquire_owned().await;
+
+            (
+                index,
+                load_input(year, day, source.as_ref(), client.as_deref()).await,
+            )
+        });
+    }
+
+    let mut inputs = vec![String::new(); days.len()];
+
+    while let Some(task) = tasks.join_next().await {
+        let (index, input) = task.map_err(|e| format!("Failed to load an input: {}", e))?;
+        inputs[index] = input?;
+    }
+
+    Ok(inputs)
+}
+
+/// Reads the input of a day, downloading the real input when it is missing, with `client` or
+/// else a client of its own. Trailing line breaks are removed, since the solutions expect inputs
+/// to end like the examples do.
+async fn load_input(
+    year: u16,
+    day: usize,
+    source: Option<&InputSource>,
+    client: Option<&Client>,
+) -> Result<String, String> {
+    let input = match source {
+        Some(InputSource::Stdin) => utils::read_stdin()?,
+        Some(InputSource::File(path)) => utils::read_input_file(&path.to_string_lossy())?,
+        None => {
+            let path = utils::input_path(year, day);
+
+            if !Path::new(&path).exists() {
+                match client {
+                    Some(client) => client.download_input(year, day, Path::new(&path)).await?,
+                    None => {
+                        Client::from_env()?
+                            .download_input(year, day, Path::new(&path))
+                            .await?
+                    }
+                }
+            }
+
+            utils::read_input_file(&path)?
+        }
+    };
+
+    Ok(input.trim_end_matches(['\n', '\r']).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_solve_days_keeps_the_order() {
+        let mut order = vec![];
+
+        // Earlier indices take longer, so they finish after the later ones
+        solve_days(
+            4,
+            8,
+            |index| {
+                thread::sleep(Duration::from_millis(5 * (8 - index as u64)));
+                index
+            },
+            |index| order.push(index),
+        );
+
+        assert_eq!(order, (0..8).collect::<Vec<_>>());
+    }
+}
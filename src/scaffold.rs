@@ -0,0 +1,133 @@
+use crate::answers::Answers;
+use crate::solution::Part;
+
+const TEMPLATE: &str = include_str!("../templates/day.rs.template");
+
+pub fn module_path(year: u16, day: usize) -> String {
+    format!("src/year_{}/day_{}.rs", year, day)
+}
+
+/// The `mod.rs` of a year, which declares its days and lists them in `DAYS`.
+pub fn registry_path(year: u16) -> String {
+    format!("src/year_{}/mod.rs", year)
+}
+
+/// Fills in the day template. The tests expect the example answers when they are known, numeric
+/// answers only since the template solves both parts with a number.
+pub fn render(year: u16, day: usize, answers: &Answers, p2_test: bool) -> String {
+    let answer = |part| {
+        answers
+            .get(part)
+            .filter(|a| a.parse::<i64>().is_ok())
+            .unwrap_or("0")
+    };
+
+    let p2_test_input = if p2_test {
+        format!(
+            "    const P2_TEST_INPUT: &str = include_str!(\"../../inputs/{}/day_{}/p2_test\");\n",
+            year, day
+        )
+    } else {
+        String::new()
+    };
+
+    TEMPLATE
+        .replace("{{p2_test_input}}\n", &p2_test_input)
+        .replace(
+            "{{part2_input}}",
+            if p2_test {
+                "P2_TEST_INPUT"
+            } else {
+                "TEST_INPUT"
+            },
+        )
+        .replace("{{part1_answer}}", answer(Part::One))
+        .replace("{{part2_answer}}", answer(Part::Two))
+        .replace("{{year}}", &year.to_string())
+        .replace("{{day}}", &day.to_string())
+}
+
+/// Adds a day to the source of a year's `mod.rs`: the `pub mod` declaration in sorted order,
+/// unless it is already there, and its entry in `DAYS`, which has to be `None` until then.
+pub fn register(source: &str, day: usize) -> Result<String, String> {
+    let name = format!("pub mod day_{}", day);
+    let module = format!("{};", name);
+    let mut lines: Vec<String> = source.lines().map(|l| l.to_string()).collect();
+
+    if !lines.contains(&module) {
+        let position = lines
+            .iter()
+            .position(|l| l.starts_with("pub mod ") && l.trim_end_matches(';') > name.as_str())
+            .or_else(|| {
+                lines
+                    .iter()
+                    .rposition(|l| l.starts_with("pub mod "))
+                    .map(|p| p + 1)
+            })
+            .ok_or("mod.rs does not declare any day")?;
+
+        lines.insert(position, module);
+    }
+
+    let table = lines
+        .iter()
+        .position(|l| l.starts_with("pub const DAYS"))
+        .ok_or("mod.rs has no DAYS table")?;
+    let entry = table + day;
+    let solution = format!("Some(&day_{}::Day{}),", day, day);
+
+    match lines.get(entry).map(|l| l.trim()) {
+        Some("None,") => lines[entry] = format!("    {}", solution),
+        Some(line) if line == solution => {}
+        _ => return Err(format!("Entry {} of DAYS is not `None,`", day)),
+    }
+
+    Ok(lines.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MOD_RS: &str = "use crate::solution::Runnable;
+
+pub mod day_1;
+pub mod day_3;
+
+pub const DAYS: [Option<&dyn Runnable>; 3] = [
+    Some(&day_1::Day1),
+    None,
+    Some(&day_3::Day3),
+];
+";
+
+    #[test]
+    pub fn test_register() {
+        let registered = register(MOD_RS, 2).unwrap();
+
+        assert!(registered.contains("pub mod day_1;\npub mod day_2;\npub mod day_3;\n"));
+        assert!(registered.contains("    Some(&day_1::Day1),\n    Some(&day_2::Day2),\n"));
+        assert_eq!(register(&registered, 2), Ok(registered.clone()));
+        assert_eq!(register(MOD_RS, 3), Ok(MOD_RS.to_string()));
+        assert!(register(MOD_RS, 4).is_err());
+    }
+
+    #[test]
+    pub fn test_render() {
+        let mut answers = Answers::default();
+        answers.set(Part::One, "11");
+        answers.set(Part::Two, "a,b");
+
+        let source = render(2024, 21, &answers, false);
+
+        assert!(source.contains("pub struct Day21;"));
+        assert!(source.contains("include_str!(\"../../inputs/2024/day_21/test\")"));
+        assert!(source.contains("assert_eq!(part1(&parse_input(TEST_INPUT).unwrap()), 11);"));
+        assert!(source.contains("assert_eq!(part2(&parse_input(TEST_INPUT).unwrap()), 0);"));
+        assert!(!source.contains("{{"));
+
+        let source = render(2024, 21, &answers, true);
+
+        assert!(source.contains("assert_eq!(part2(&parse_input(P2_TEST_INPUT).unwrap()), 0);"));
+    }
+}
@@ -0,0 +1,141 @@
+use crate::context::Context;
+use crate::utils::parse::ParseError;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::any::Any;
+use std::fmt::{Display, Formatter};
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+impl Serialize for Part {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Part::One => serializer.serialize_u8(1),
+            Part::Two => serializer.serialize_u8(2),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Part {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match u8::deserialize(deserializer)? {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            part => Err(serde::de::Error::custom(format!(
+                "part {} does not exist",
+                part
+            ))),
+        }
+    }
+}
+
+/// The answer to a single part, either a number or a string like day 17's comma separated output.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Answer::Number(n) => serializer.serialize_i128(*n),
+            Answer::Text(s) => serializer.serialize_str(s),
+        }
+    }
+}
+
+macro_rules! impl_answer_from_number {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::Number(n as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_answer_from_number!(i32, i64, isize, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+/// A single day of the puzzle. The input is parsed once and both parts are solved from the
+/// parsed value, so the runner can time and report every step separately. The parts log and draw
+/// through the [`Context`] instead of printing.
+pub trait Solution {
+    type Parsed: 'static;
+
+    /// The parts this day has a solution for, the runner will never ask for the others.
+    const PARTS: &'static [Part] = &[Part::One, Part::Two];
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError>;
+
+    fn part1(&self, parsed: &Self::Parsed, context: &Context) -> Answer;
+
+    fn part2(&self, _parsed: &Self::Parsed, _context: &Context) -> Answer {
+        unreachable!("Part 2 is not solved for this day")
+    }
+}
+
+/// Object safe version of [`Solution`] so days with different parsed types fit in one registry.
+pub trait Runnable: Sync {
+    fn parts(&self) -> &'static [Part];
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError>;
+
+    fn solve(&self, parsed: &dyn Any, part: Part, context: &Context) -> Answer;
+}
+
+impl<S: Solution + Sync> Runnable for S {
+    fn parts(&self) -> &'static [Part] {
+        S::PARTS
+    }
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>, ParseError> {
+        Ok(Box::new(Solution::parse(self, input)?))
+    }
+
+    fn solve(&self, parsed: &dyn Any, part: Part, context: &Context) -> Answer {
+        let parsed = parsed
+            .downcast_ref::<S::Parsed>()
+            .expect("Parsed input belongs to a different day");
+
+        match part {
+            Part::One => self.part1(parsed, context),
+            Part::Two => self.part2(parsed, context),
+        }
+    }
+}
@@ -0,0 +1,372 @@
+use crate::solution::Part;
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+
+pub const PATH: &str = ".aoc/attempts";
+
+lazy_static! {
+    static ref ARTICLE: Regex = Regex::new(r"(?s)<article>(.*?)</article>").unwrap();
+    static ref TAG: Regex = Regex::new(r"<[^>]*>").unwrap();
+    static ref WRONG_WAIT: Regex = Regex::new(r"(?i)please wait (one|\d+) minutes?").unwrap();
+    static ref RECENT_WAIT: Regex =
+        Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap();
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+/// What adventofcode.com said about a submitted answer.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(tag = "outcome", rename_all = "snake_case")]
+pub enum Outcome {
+    Correct,
+    Incorrect {
+        hint: Option<Hint>,
+        wait_seconds: Option<u64>,
+    },
+    TooRecent {
+        wait_seconds: u64,
+    },
+    /// The part is locked or was already solved.
+    WrongLevel,
+    Unknown {
+        message: String,
+    },
+}
+
+impl Outcome {
+    pub fn wait_seconds(&self) -> Option<u64> {
+        match self {
+            Outcome::Incorrect { wait_seconds, .. } => *wait_seconds,
+            Outcome::TooRecent { wait_seconds } => Some(*wait_seconds),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "That's the right answer!"),
+            Outcome::Incorrect { hint, wait_seconds } => {
+                write!(f, "That's not the right answer")?;
+
+                match hint {
+                    Some(Hint::TooHigh) => write!(f, ", it is too high")?,
+                    Some(Hint::TooLow) => write!(f, ", it is too low")?,
+                    None => {}
+                }
+
+                match wait_seconds {
+                    Some(seconds) => write!(f, ". Wait {}s before trying again", seconds),
+                    None => Ok(()),
+                }
+            }
+            Outcome::TooRecent { wait_seconds } => write!(
+                f,
+                "You gave an answer too recently, wait {}s before trying again",
+                wait_seconds
+            ),
+            Outcome::WrongLevel => write!(f, "This part is not unlocked yet or was already solved"),
+            Outcome::Unknown { message } => write!(f, "Unexpected response: {}", message),
+        }
+    }
+}
+
+/// Parses the page adventofcode.com responds with after posting an answer.
+pub fn parse_response(html: &str) -> Outcome {
+    let article = ARTICLE
+        .captures(html)
+        .map(|c| c[1].to_string())
+        .unwrap_or_else(|| html.to_string());
+    let text = TAG.replace_all(&article, "");
+
+    if text.contains("That's the right answer") {
+        return Outcome::Correct;
+    }
+
+    if text.contains("That's not the right answer") {
+        let hint = if text.contains("too high") {
+            Some(Hint::TooHigh)
+        } else if text.contains("too low") {
+            Some(Hint::TooLow)
+        } else {
+            None
+        };
+
+        let wait_seconds = WRONG_WAIT.captures(&text).map(|c| match &c[1] {
+            "one" => 60,
+            minutes => minutes.parse::<u64>().unwrap() * 60,
+        });
+
+        return Outcome::Incorrect { hint, wait_seconds };
+    }
+
+    if text.contains("You gave an answer too recently") {
+        let wait_seconds = RECENT_WAIT
+            .captures(&text)
+            .map(|c| {
+                let minutes = c.get(1).map_or(0, |m| m.as_str().parse::<u64>().unwrap());
+                let seconds = c[2].parse::<u64>().unwrap();
+
+                minutes * 60 + seconds
+            })
+            .unwrap_or(60);
+
+        return Outcome::TooRecent { wait_seconds };
+    }
+
+    if text.contains("You don't seem to be solving the right level") {
+        return Outcome::WrongLevel;
+    }
+
+    Outcome::Unknown {
+        message: text.split_whitespace().collect::<Vec<_>>().join(" "),
+    }
+}
+
+/// A submitted answer, stored as one JSON line in the attempts file.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct Attempt {
+    pub year: u16,
+    pub day: usize,
+    pub part: Part,
+    pub answer: String,
+    pub timestamp: u64,
+    #[serde(flatten)]
+    pub outcome: Outcome,
+}
+
+impl Attempt {
+    fn retry_after(&self) -> Option<u64> {
+        self.outcome
+            .wait_seconds()
+            .map(|wait| self.timestamp + wait)
+    }
+}
+
+pub fn load(path: &Path) -> Result<Vec<Attempt>, String> {
+    if !path.exists() {
+        return Ok(vec![]);
+    }
+
+    let content = fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+
+    content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(i, line)| {
+            serde_json::from_str(line).map_err(|e| {
+                format!(
+                    "Line {} of {} is not a valid attempt: {}",
+                    i + 1,
+                    path.display(),
+                    e
+                )
+            })
+        })
+        .collect()
+}
+
+pub fn record(path: &Path, attempt: &Attempt) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)
+            .map_err(|e| format!("Failed to create {}: {}", dir.display(), e))?;
+    }
+
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(|e| format!("Failed to open {}: {}", path.display(), e))?;
+    let line = serde_json::to_string(attempt).expect("Attempts are valid JSON");
+
+    writeln!(file, "{}", line).map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+}
+
+/// Refuses answers that are known to be wrong without asking, either because the exact answer
+/// was rejected before or because it is outside the bounds of earlier too high/too low hints.
+/// Also refuses while a cooldown of the day is still running.
+pub fn check(
+    attempts: &[Attempt],
+    year: u16,
+    day: usize,
+    part: Part,
+    answer: &str,
+    now: u64,
+) -> Result<(), String> {
+    let previous: Vec<&Attempt> = attempts
+        .iter()
+        .filter(|a| a.year == year && a.day == day && a.part == part)
+        .collect();
+
+    if let Some(solved) = previous.iter().find(|a| a.outcome == Outcome::Correct) {
+        return Err(format!(
+            "Part {} of day {} was already solved with {}",
+            part, day, solved.answer
+        ));
+    }
+
+    for attempt in &previous {
+        let Outcome::Incorrect { hint, .. } = attempt.outcome else {
+            continue;
+        };
+
+        if attempt.answer == answer {
+            return Err(format!("{} was already submitted and was wrong", answer));
+        }
+
+        let (Ok(tried), Ok(value)) = (attempt.answer.parse::<i128>(), answer.parse::<i128>())
+        else {
+            continue;
+        };
+
+        match hint {
+            Some(Hint::TooHigh) if value >= tried => {
+                return Err(format!("{} is too high, {} already was", answer, tried))
+            }
+            Some(Hint::TooLow) if value <= tried => {
+                return Err(format!("{} is too low, {} already was", answer, tried))
+            }
+            _ => {}
+        }
+    }
+
+    let retry_after = attempts
+        .iter()
+        .filter(|a| a.year == year && a.day == day)
+        .filter_map(|a| a.retry_after())
+        .max();
+
+    if let Some(retry_after) = retry_after.filter(|r| *r > now) {
+        return Err(format!(
+            "Wait another {}s before submitting an answer for day {}",
+            retry_after - now,
+            day
+        ));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::Client;
+    use crate::mock_server;
+
+    const CORRECT: &str = "<main><article><p>That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer to finding the Chief Historian.</p></article></main>";
+    const TOO_HIGH: &str = "<main><article><p>That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data. Please wait one minute before trying again. <a href=\"/2024/day/1\">[Return to Day 1]</a></p></article></main>";
+    const WRONG: &str = "<article><p>That's not the right answer.  Because you have guessed incorrectly 5 times on this puzzle, please wait 5 minutes before trying again.</p></article>";
+    const TOO_RECENT: &str = "<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 12s left to wait. <a href=\"/2024/day/1\">[Return to Day 1]</a></p></article>";
+    const WRONG_LEVEL: &str = "<article><p>You don't seem to be solving the right level.  Did you already complete it? <a href=\"/2024/day/1\">[Return to Day 1]</a></p></article>";
+
+    fn attempt(part: Part, answer: &str, timestamp: u64, outcome: Outcome) -> Attempt {
+        Attempt {
+            year: 2024,
+            day: 1,
+            part,
+            answer: answer.to_string(),
+            timestamp,
+            outcome,
+        }
+    }
+
+    #[test]
+    pub fn test_parse_response() {
+        assert_eq!(parse_response(CORRECT), Outcome::Correct);
+        assert_eq!(
+            parse_response(TOO_HIGH),
+            Outcome::Incorrect {
+                hint: Some(Hint::TooHigh),
+                wait_seconds: Some(60)
+            }
+        );
+        assert_eq!(
+            parse_response(WRONG),
+            Outcome::Incorrect {
+                hint: None,
+                wait_seconds: Some(300)
+            }
+        );
+        assert_eq!(
+            parse_response(TOO_RECENT),
+            Outcome::TooRecent { wait_seconds: 252 }
+        );
+        assert_eq!(parse_response(WRONG_LEVEL), Outcome::WrongLevel);
+        assert_eq!(
+            parse_response("<article><p>Something <em>else</em></p></article>"),
+            Outcome::Unknown {
+                message: "Something else".to_string()
+            }
+        );
+    }
+
+    #[test]
+    pub fn test_check() {
+        let too_high = Outcome::Incorrect {
+            hint: Some(Hint::TooHigh),
+            wait_seconds: Some(60),
+        };
+        let attempts = vec![attempt(Part::One, "500", 1000, too_high)];
+
+        assert!(check(&attempts, 2024, 1, Part::One, "500", 2000).is_err());
+        assert!(check(&attempts, 2024, 1, Part::One, "600", 2000).is_err());
+        assert!(check(&attempts, 2024, 1, Part::One, "400", 1030).is_err());
+        assert!(check(&attempts, 2024, 1, Part::One, "400", 2000).is_ok());
+        assert!(check(&attempts, 2024, 1, Part::Two, "500", 2000).is_ok());
+        assert!(check(&attempts, 2024, 2, Part::One, "500", 1030).is_ok());
+
+        let attempts = vec![attempt(Part::One, "400", 1000, Outcome::Correct)];
+
+        assert!(check(&attempts, 2024, 1, Part::One, "400", 2000).is_err());
+    }
+
+    #[test]
+    pub fn test_attempts_round_trip() {
+        let attempt = attempt(
+            Part::Two,
+            "4,6,3",
+            1000,
+            Outcome::Incorrect {
+                hint: None,
+                wait_seconds: Some(60),
+            },
+        );
+        let line = serde_json::to_string(&attempt).unwrap();
+
+        assert_eq!(
+            line,
+            r#"{"year":2024,"day":1,"part":2,"answer":"4,6,3","timestamp":1000,"outcome":"incorrect","hint":null,"wait_seconds":60}"#
+        );
+        assert_eq!(serde_json::from_str::<Attempt>(&line).unwrap(), attempt);
+    }
+
+    #[tokio::test]
+    pub async fn test_submit_to_mock_server() {
+        let (base_url, server) = mock_server::serve(vec![(200, TOO_HIGH)]).await;
+
+        let html = Client::new(&base_url, "secret")
+            .submit(2024, 1, Part::Two, "42")
+            .await
+            .unwrap();
+        let requests = server.await.unwrap();
+
+        assert_eq!(parse_response(&html).wait_seconds(), Some(60));
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].path, "/2024/day/1/answer");
+        assert_eq!(requests[0].header("cookie"), Some("session=secret"));
+        assert_eq!(requests[0].body, "level=2&answer=42");
+    }
+}
@@ -0,0 +1,113 @@
+use crate::utils;
+use serde::Serialize;
+use std::cell::{Cell, RefCell};
+use std::time::Duration;
+
+thread_local! {
+    static PHASES: RefCell<Vec<Phase>> = const { RefCell::new(vec![]) };
+    /// All the time spent in [`paused`] on this thread so far.
+    static PAUSED: Cell<Duration> = const { Cell::new(Duration::ZERO) };
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct Phase {
+    pub name: &'static str,
+    #[serde(rename = "ns", serialize_with = "as_nanos")]
+    pub duration: Duration,
+}
+
+/// How long a step (parsing or solving a part) took, including the phases a solution marked
+/// with [`phase`] while it ran.
+#[derive(Clone, Debug, Default)]
+pub struct Timing {
+    pub total: Duration,
+    pub phases: Vec<Phase>,
+}
+
+/// Times `f` as a named phase of the step that is currently being measured. Phases are recorded
+/// per thread, so phases inside threads spawned by a solution are not picked up.
+pub fn phase<T, F: FnOnce() -> T>(name: &'static str, f: F) -> T {
+    let (result, duration) = time_unpaused(f);
+
+    PHASES.with(|phases| phases.borrow_mut().push(Phase { name, duration }));
+
+    result
+}
+
+/// Runs `f` and returns how long it took, together with the phases it marked.
+pub fn measure<T, F: FnOnce() -> T>(f: F) -> (T, Timing) {
+    let outer = PHASES.with(|phases| phases.take());
+
+    let (result, total) = time_unpaused(f);
+
+    let phases = PHASES.with(|phases| phases.replace(outer));
+
+    (result, Timing { total, phases })
+}
+
+/// Runs `f` without counting its time in the step and phases around it, like writing output.
+pub fn paused<T, F: FnOnce() -> T>(f: F) -> T {
+    let (result, duration) = utils::time_it(f);
+
+    PAUSED.with(|paused| paused.set(paused.get() + duration));
+
+    result
+}
+
+fn time_unpaused<T, F: FnOnce() -> T>(f: F) -> (T, Duration) {
+    let before = PAUSED.with(Cell::get);
+    let (result, duration) = utils::time_it(f);
+    let paused = PAUSED.with(Cell::get) - before;
+
+    (result, duration.saturating_sub(paused))
+}
+
+fn as_nanos<S: serde::Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u128(duration.as_nanos())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_measure_collects_phases() {
+        let (result, timing) = measure(|| {
+            let a = phase("first", || 20);
+            let b = phase("second", || 22);
+
+            a + b
+        });
+
+        assert_eq!(result, 42);
+        assert_eq!(
+            timing.phases.iter().map(|p| p.name).collect::<Vec<_>>(),
+            vec!["first", "second"]
+        );
+        assert!(timing.phases.iter().map(|p| p.duration).sum::<Duration>() <= timing.total);
+    }
+
+    #[test]
+    pub fn test_nested_measure_keeps_outer_phases() {
+        let (_, outer) = measure(|| {
+            phase("outer", || ());
+
+            let (_, inner) = measure(|| phase("inner", || ()));
+            assert_eq!(inner.phases.len(), 1);
+        });
+
+        assert_eq!(outer.phases.len(), 1);
+        assert_eq!(outer.phases[0].name, "outer");
+    }
+
+    #[test]
+    pub fn test_paused_time_is_not_counted() {
+        let pause = Duration::from_millis(50);
+        let (_, timing) = measure(|| {
+            phase("drawing", || paused(|| std::thread::sleep(pause)));
+        });
+
+        assert!(timing.total < pause);
+        assert!(timing.phases[0].duration < pause);
+    }
+}
@@ -1,4 +1,7 @@
-use std::env;
+pub mod grid;
+pub mod movement;
+pub mod parse;
+
 use std::fs::File;
 use std::io::Read;
 use std::time::Duration;
@@ -9,86 +12,49 @@ pub fn print_day_banner(day: usize) {
     println!("############");
 }
 
-pub fn time_it<F: Fn()>(f: F) -> Duration {
+pub fn time_it<T, F: FnOnce() -> T>(f: F) -> (T, Duration) {
     let start_time = std::time::Instant::now();
 
-    f();
+    let result = f();
 
-    start_time.elapsed()
+    (result, start_time.elapsed())
 }
 
 pub fn print_duration(duration: Duration, day: usize) {
-    let ms = duration.as_millis();
-
-    // When the code is too fast, switch to nanoseconds!
-    if ms == 0 {
-        let ns = duration.as_nanos();
-
-        println!("Day {} took {}ns\n", day, ns);
-    } else if ms > 10_000 {
-        let s = duration.as_secs();
-
-        println!("Day {} took {}s\n", day, s);
-    } else {
-        println!("Day {} took {}ms\n", day, ms);
-    }
+    println!("Day {} took {}\n", day, format_duration(duration));
 }
 
-pub async fn download_input(day: usize) {
-    use std::io::Write;
-
-    let url = format!("https://adventofcode.com/2024/day/{}/input", day);
-
-    let client = reqwest::Client::new();
-    let response = client
-        .get(&url)
-        .header("User-Agent", "advent-of-code-helper")
-        .header(
-            "Cookie",
-            format!(
-                "session={}",
-                env::var("AOC_SESSION")
-                    .expect("env var AOC_SESSION not set, cannot auto-download input")
-            ),
-        )
-        .send()
-        .await
-        .expect("Failed to send request");
-
-    if response.status().is_success() {
-        let data = response.text().await.expect("Failed to read response");
+/// Always milliseconds, with microsecond precision, so durations can be compared at a glance.
+pub fn format_duration(duration: Duration) -> String {
+    format!("{:.3}ms", duration.as_secs_f64() * 1000.0)
+}
 
-        let mut file =
-            File::create(format!("inputs/day_{}/input", day)).expect("Failed to create input file");
+/// Everything that belongs to a day of an event lives in `inputs/<year>/day_N`.
+pub fn day_dir(year: u16, day: usize) -> String {
+    format!("inputs/{}/day_{}", year, day)
+}
 
-        write!(file, "{}", data.trim_end()).expect("Could not write to file");
-    } else {
-        panic!("Failed to download input: {}", response.status());
-    }
+pub fn input_path(year: u16, day: usize) -> String {
+    format!("{}/input", day_dir(year, day))
 }
 
-pub fn read_input_file(day: usize) -> String {
-    let file_path = format!("inputs/day_{}/input", day);
-    let mut file = File::open(&file_path).expect("Input file not found");
+pub fn read_input_file(file_path: &str) -> Result<String, String> {
+    let mut file =
+        File::open(file_path).map_err(|e| format!("Input file {} not found: {}", file_path, e))?;
     let mut data = String::new();
 
     file.read_to_string(&mut data)
-        .expect(format!("Failed to read input file: {}", &file_path).as_str());
+        .map_err(|e| format!("Failed to read input file {}: {}", file_path, e))?;
 
-    data
+    Ok(data)
 }
 
-pub fn input_to_char_matrix(input: &str) -> Vec<Vec<char>> {
-    input.lines().map(|line| line.chars().collect()).collect()
-}
+pub fn read_stdin() -> Result<String, String> {
+    let mut data = String::new();
+
+    std::io::stdin()
+        .read_to_string(&mut data)
+        .map_err(|e| format!("Failed to read input from stdin: {}", e))?;
 
-pub fn input_to_usize_matrix(input: &str) -> Vec<Vec<usize>> {
-    input
-        .lines()
-        .map(|l| {
-            l.chars()
-                .filter_map(|c| c.to_digit(10).map(|d| d as usize))
-                .collect()
-        })
-        .collect()
+    Ok(data)
 }
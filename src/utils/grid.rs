@@ -0,0 +1,274 @@
+use crate::utils::movement::{Direction, Point};
+use crate::utils::parse::ParseError;
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut};
+
+/// A rectangular grid stored row by row in a single `Vec`.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Builds a grid from its rows, which all have to be equally long.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Grid<T> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |row| row.len());
+
+        assert!(
+            rows.iter().all(|row| row.len() == width),
+            "Not every row of the grid has {} columns",
+            width
+        );
+
+        Grid {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    /// Parses every line of the input into a row, converting each character with `cell`. Fails
+    /// on characters `cell` does not accept and on rows that are not as long as the first.
+    pub fn parse<F: FnMut(char) -> Option<T>>(
+        input: &str,
+        mut cell: F,
+    ) -> Result<Grid<T>, ParseError> {
+        let mut rows: Vec<Vec<T>> = vec![];
+
+        for line in input.lines() {
+            let row = line
+                .char_indices()
+                .map(|(i, c)| {
+                    cell(c).ok_or_else(|| {
+                        ParseError::at(input, &line[i..], format!("unexpected `{}`", c))
+                    })
+                })
+                .collect::<Result<Vec<T>, ParseError>>()?;
+
+            if let Some(first) = rows.first() {
+                if row.len() != first.len() {
+                    return Err(ParseError::at(
+                        input,
+                        line,
+                        format!("expected {} columns, found {}", first.len(), row.len()),
+                    ));
+                }
+            }
+
+            rows.push(row);
+        }
+
+        Ok(Grid::from_rows(rows))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        point.is_within(self.width, self.height)
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.index_of(point).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.index_of(point).map(|i| &mut self.cells[i])
+    }
+
+    /// Every point of the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+
+        (0..self.width * self.height)
+            .map(move |i| Point::new((i % width) as isize, (i / width) as isize))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The points next to `point` in the given directions that are on the grid.
+    pub fn neighbours<'a>(
+        &'a self,
+        point: Point,
+        directions: &'a [Direction],
+    ) -> impl Iterator<Item = (Point, &'a T)> + 'a {
+        directions.iter().filter_map(move |&direction| {
+            let next = point.step(direction);
+
+            self.get(next).map(|value| (next, value))
+        })
+    }
+
+    pub fn find(&self, value: &T) -> Option<Point>
+    where
+        T: PartialEq,
+    {
+        self.iter().find(|(_, v)| *v == value).map(|(p, _)| p)
+    }
+
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Point> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(move |(_, v)| *v == value)
+            .map(|(p, _)| p)
+    }
+
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Mirrors the grid along its diagonal, rows become columns.
+    #[allow(dead_code)]
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.rebuild(self.height, self.width, |x, y| Point::new(y, x))
+    }
+
+    /// Rotates the grid a quarter clockwise.
+    #[allow(dead_code)]
+    pub fn rotate_right(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let height = self.height as isize;
+
+        self.rebuild(self.height, self.width, |x, y| {
+            Point::new(y, height - 1 - x)
+        })
+    }
+
+    /// Builds a grid of the given size, taking every cell from the point `source` maps it to.
+    fn rebuild<F: Fn(isize, isize) -> Point>(
+        &self,
+        width: usize,
+        height: usize,
+        source: F,
+    ) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let mut cells = Vec::with_capacity(self.cells.len());
+
+        for y in 0..height as isize {
+            for x in 0..width as isize {
+                cells.push(self[source(x, y)].clone());
+            }
+        }
+
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    fn index_of(&self, point: Point) -> Option<usize> {
+        self.contains(point)
+            .then(|| point.y as usize * self.width + point.x as usize)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{:?} is outside of the grid", point))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{:?} is outside of the grid", point))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "abc\ndef";
+
+    #[test]
+    pub fn test_grid() {
+        let grid = Grid::parse(INPUT, Some).unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point::new(2, 1)], 'f');
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.get(Point::new(0, -1)), None);
+        assert_eq!(grid.find(&'e'), Some(Point::new(1, 1)));
+        assert_eq!(grid.to_string(), "abc\ndef\n");
+        assert_eq!(
+            Grid::parse("ab\nc", Some).unwrap_err().to_string(),
+            "line 2, column 1: expected 2 columns, found 1"
+        );
+        assert_eq!(
+            Grid::parse("12\n3x", |c| c.to_digit(10)).unwrap_err(),
+            ParseError::new(2, 2, "unexpected `x`")
+        );
+
+        let neighbours: Vec<char> = grid
+            .neighbours(Point::new(0, 0), &Direction::ALL)
+            .map(|(_, c)| *c)
+            .collect();
+        assert_eq!(neighbours, vec!['b', 'e', 'd']);
+    }
+
+    #[test]
+    pub fn test_transpose_and_rotate() {
+        let grid = Grid::parse(INPUT, Some).unwrap();
+
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf\n");
+        assert_eq!(grid.rotate_right().to_string(), "da\neb\nfc\n");
+        assert_eq!(grid.rotate_right().rotate_right().to_string(), "fed\ncba\n");
+    }
+}
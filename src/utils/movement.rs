@@ -0,0 +1,224 @@
+use std::ops::{Add, AddAssign, Mul, Sub, SubAssign};
+
+/// A position on a map, `x` grows to the right and `y` grows down like the lines of an input.
+/// Coordinates are signed, so stepping off a map is just a point it does not contain.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Point {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Point {
+    pub const fn new(x: isize, y: isize) -> Point {
+        Point { x, y }
+    }
+
+    pub fn manhattan(self, other: Point) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    pub fn step(self, direction: Direction) -> Point {
+        self + direction.offset()
+    }
+
+    /// Steps in `direction`, unless that leaves the `width` by `height` area at the origin.
+    pub fn checked_step(self, direction: Direction, width: usize, height: usize) -> Option<Point> {
+        Some(self.step(direction)).filter(|next| next.is_within(width, height))
+    }
+
+    /// Steps in `direction`, leaving the `width` by `height` area at the origin on one side
+    /// enters it again on the other.
+    #[allow(dead_code)]
+    pub fn wrapping_step(self, direction: Direction, width: usize, height: usize) -> Point {
+        self.step(direction).wrap(width, height)
+    }
+
+    /// Moves the point into the `width` by `height` area at the origin as if its edges were
+    /// connected, so any offset can be added first and wrapped once.
+    pub fn wrap(self, width: usize, height: usize) -> Point {
+        Point::new(
+            self.x.rem_euclid(width as isize),
+            self.y.rem_euclid(height as isize),
+        )
+    }
+
+    pub fn is_within(self, width: usize, height: usize) -> bool {
+        self.x >= 0 && self.y >= 0 && (self.x as usize) < width && (self.y as usize) < height
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<isize> for Point {
+    type Output = Point;
+
+    fn mul(self, factor: isize) -> Point {
+        Point::new(self.x * factor, self.y * factor)
+    }
+}
+
+/// The 8 compass headings, in clockwise order starting at the top.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Direction {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction {
+    pub const ORTHOGONAL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub const ALL: [Direction; 8] = [
+        Direction::Up,
+        Direction::UpRight,
+        Direction::Right,
+        Direction::DownRight,
+        Direction::Down,
+        Direction::DownLeft,
+        Direction::Left,
+        Direction::UpLeft,
+    ];
+
+    /// Reads a heading written as an arrow (`^`, `>`, `v`, `<`) or a compass point (`N`, `E`,
+    /// `S`, `W`).
+    pub fn from_char(c: char) -> Option<Direction> {
+        match c {
+            '^' | 'N' => Some(Direction::Up),
+            '>' | 'E' => Some(Direction::Right),
+            'v' | 'S' => Some(Direction::Down),
+            '<' | 'W' => Some(Direction::Left),
+            _ => None,
+        }
+    }
+
+    pub fn arrow(self) -> char {
+        match self {
+            Direction::Up => '^',
+            Direction::UpRight => '↗',
+            Direction::Right => '>',
+            Direction::DownRight => '↘',
+            Direction::Down => 'v',
+            Direction::DownLeft => '↙',
+            Direction::Left => '<',
+            Direction::UpLeft => '↖',
+        }
+    }
+
+    pub fn offset(self) -> Point {
+        match self {
+            Direction::Up => Point::new(0, -1),
+            Direction::UpRight => Point::new(1, -1),
+            Direction::Right => Point::new(1, 0),
+            Direction::DownRight => Point::new(1, 1),
+            Direction::Down => Point::new(0, 1),
+            Direction::DownLeft => Point::new(-1, 1),
+            Direction::Left => Point::new(-1, 0),
+            Direction::UpLeft => Point::new(-1, -1),
+        }
+    }
+
+    /// Turns a quarter clockwise.
+    pub fn turn_right(self) -> Direction {
+        self.rotate(2)
+    }
+
+    /// Turns a quarter counterclockwise.
+    #[allow(dead_code)]
+    pub fn turn_left(self) -> Direction {
+        self.rotate(6)
+    }
+
+    pub fn opposite(self) -> Direction {
+        self.rotate(4)
+    }
+
+    /// Rotates clockwise in steps of an eighth.
+    fn rotate(self, eighths: usize) -> Direction {
+        Direction::ALL[(self as usize + eighths) % Direction::ALL.len()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_turning() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Left.turn_right(), Direction::Up);
+        assert_eq!(Direction::UpLeft.turn_left(), Direction::DownLeft);
+        assert_eq!(Direction::DownRight.opposite(), Direction::UpLeft);
+    }
+
+    #[test]
+    pub fn test_parsing() {
+        let headings: Vec<Direction> = "^>v<NESW"
+            .chars()
+            .filter_map(Direction::from_char)
+            .collect();
+
+        assert_eq!(headings[..4], Direction::ORTHOGONAL);
+        assert_eq!(headings[4..], Direction::ORTHOGONAL);
+        assert_eq!(Direction::from_char('x'), None);
+        assert_eq!(
+            Direction::ORTHOGONAL.map(Direction::arrow),
+            ['^', '>', 'v', '<']
+        );
+    }
+
+    #[test]
+    pub fn test_stepping() {
+        let corner = Point::new(0, 0);
+
+        assert_eq!(corner.step(Direction::UpLeft), Point::new(-1, -1));
+        assert_eq!(corner.checked_step(Direction::Up, 3, 2), None);
+        assert_eq!(
+            corner.checked_step(Direction::DownRight, 3, 2),
+            Some(Point::new(1, 1))
+        );
+        assert_eq!(
+            corner.wrapping_step(Direction::UpLeft, 3, 2),
+            Point::new(2, 1)
+        );
+        assert_eq!(
+            (corner + Point::new(2, -3) * 4).wrap(3, 2),
+            Point::new(2, 0)
+        );
+    }
+}
@@ -0,0 +1,276 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// Where and why an input could not be parsed. Lines and columns count from 1, the day is filled
+/// in by the runner since the parsers do not know which day they belong to.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseError {
+    pub day: Option<usize>,
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, message: impl Into<String>) -> ParseError {
+        ParseError {
+            day: None,
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    /// An error about `fragment` at the position where it starts in `input`. The fragment has to
+    /// be a slice of the input, like the lines and fields split from it.
+    pub fn at(input: &str, fragment: &str, message: impl Into<String>) -> ParseError {
+        let offset = (fragment.as_ptr() as usize)
+            .saturating_sub(input.as_ptr() as usize)
+            .min(input.len());
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+
+        ParseError::new(
+            before.matches('\n').count() + 1,
+            before[line_start..].chars().count() + 1,
+            message,
+        )
+    }
+
+    pub fn in_day(self, day: usize) -> ParseError {
+        ParseError {
+            day: Some(day),
+            ..self
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day {}, ", day)?;
+        }
+
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl From<ParseError> for String {
+    fn from(error: ParseError) -> Self {
+        error.to_string()
+    }
+}
+
+/// Parses `text`, a slice of `input`, as a number.
+pub fn number<T: FromStr>(input: &str, text: &str) -> Result<T, ParseError> {
+    text.parse()
+        .map_err(|_| ParseError::at(input, text, format!("expected a number, found `{}`", text)))
+}
+
+/// Splits `text`, a slice of `input`, around the first `separator`.
+pub fn split_once<'a>(
+    input: &str,
+    text: &'a str,
+    separator: &str,
+) -> Result<(&'a str, &'a str), ParseError> {
+    text.split_once(separator).ok_or_else(|| {
+        ParseError::at(
+            input,
+            text,
+            format!("expected `{}` in `{}`", separator, text),
+        )
+    })
+}
+
+/// Splits `text`, a slice of `input`, around the first `:` into a trimmed key and value, like the
+/// `Register A: 729` and `x00: 1` lines.
+pub fn key_value<'a>(input: &str, text: &'a str) -> Result<(&'a str, &'a str), ParseError> {
+    let (key, value) = text.split_once(':').ok_or_else(|| {
+        ParseError::at(
+            input,
+            text,
+            format!("expected `key: value`, found `{}`", text),
+        )
+    })?;
+
+    Ok((key.trim(), value.trim()))
+}
+
+/// Every integer in `text`, a slice of `input`, skipping whatever is around them. A `-` right
+/// before the digits makes the number negative, so `p=0,4 v=3,-3` gives `0, 4, 3, -3`.
+pub fn integers<T: FromStr>(input: &str, text: &str) -> Result<Vec<T>, ParseError> {
+    let bytes = text.as_bytes();
+    let mut numbers = vec![];
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if !bytes[i].is_ascii_digit() {
+            i += 1;
+            continue;
+        }
+
+        let start = if i > 0 && bytes[i - 1] == b'-' {
+            i - 1
+        } else {
+            i
+        };
+
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+
+        numbers.push(number(input, &text[start..i])?);
+    }
+
+    Ok(numbers)
+}
+
+/// Exactly `N` integers from `text`, a slice of `input`.
+pub fn integers_exact<T: FromStr, const N: usize>(
+    input: &str,
+    text: &str,
+) -> Result<[T; N], ParseError> {
+    exact(input, text, integers(input, text)?, "numbers")
+}
+
+/// Parses the values of a `separator` separated list in `text`, a slice of `input`.
+pub fn list<T: FromStr>(input: &str, text: &str, separator: &str) -> Result<Vec<T>, ParseError> {
+    text.split(separator)
+        .map(|value| number(input, value.trim()))
+        .collect()
+}
+
+/// Parses every non-empty line of `text` with `line`.
+pub fn lines<'a, T, F>(text: &'a str, line: F) -> Result<Vec<T>, ParseError>
+where
+    F: FnMut(&'a str) -> Result<T, ParseError>,
+{
+    text.lines()
+        .filter(|l| !l.trim().is_empty())
+        .map(line)
+        .collect()
+}
+
+/// The blocks of lines in `text` that are separated by blank lines, as slices of it.
+pub fn sections(text: &str) -> Vec<&str> {
+    let mut sections = vec![];
+    let mut current: Option<(usize, usize)> = None;
+    let mut offset = 0;
+
+    for line in text.split_inclusive('\n') {
+        let content = line.trim_end_matches(['\r', '\n']);
+
+        if content.trim().is_empty() {
+            if let Some((start, end)) = current.take() {
+                sections.push(&text[start..end]);
+            }
+        } else {
+            let (start, _) = current.unwrap_or((offset, offset));
+            current = Some((start, offset + content.len()));
+        }
+
+        offset += line.len();
+    }
+
+    if let Some((start, end)) = current {
+        sections.push(&text[start..end]);
+    }
+
+    sections
+}
+
+/// Exactly `N` blank line separated sections of `input`.
+pub fn sections_exact<const N: usize>(input: &str) -> Result<[&str; N], ParseError> {
+    exact(input, input, sections(input), "sections")
+}
+
+/// Turns `items` found in `text` into an array, failing when there are not exactly `N`.
+fn exact<T, const N: usize>(
+    input: &str,
+    text: &str,
+    items: Vec<T>,
+    what: &str,
+) -> Result<[T; N], ParseError> {
+    let found = items.len();
+
+    items.try_into().map_err(|_| {
+        ParseError::at(
+            input,
+            text,
+            format!("expected {} {}, found {}", N, what, found),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_error_position() {
+        let input = "1 2\n3 x\r\n5 6";
+        let line = input.lines().nth(1).unwrap();
+        let field = line.split_whitespace().nth(1).unwrap();
+
+        let error = number::<u32>(input, field).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(
+            error.in_day(4).to_string(),
+            "day 4, line 2, column 3: expected a number, found `x`"
+        );
+
+        let line = input.lines().nth(2).unwrap();
+        assert_eq!(number::<u32>(input, &line[2..]), Ok(6));
+        assert_eq!(
+            split_once(input, line, ",").unwrap_err().to_string(),
+            "line 3, column 1: expected `,` in `5 6`"
+        );
+    }
+
+    #[test]
+    pub fn test_integers() {
+        let input = "p=0,4 v=3,-3\nButton A: X+94, Y+34";
+        let (first, second) = input.split_once('\n').unwrap();
+
+        assert_eq!(integers::<isize>(input, first), Ok(vec![0, 4, 3, -3]));
+        assert_eq!(integers_exact::<u64, 2>(input, second), Ok([94, 34]));
+        assert_eq!(
+            integers_exact::<u64, 4>(input, first).unwrap_err(),
+            ParseError::new(1, 11, "expected a number, found `-3`")
+        );
+        assert_eq!(
+            integers_exact::<u64, 3>(input, second)
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 1: expected 3 numbers, found 2"
+        );
+        assert_eq!(list::<u8>(input, "1, 2,3", ","), Ok(vec![1, 2, 3]));
+    }
+
+    #[test]
+    pub fn test_sections_and_records() {
+        let input = "Register A: 729\r\nRegister B: 0\r\n\r\n\nProgram: 0,1\n\n";
+        let [registers, program] = sections_exact(input).unwrap();
+
+        assert_eq!(registers, "Register A: 729\r\nRegister B: 0");
+        assert_eq!(
+            lines(registers, |line| key_value(input, line)),
+            Ok(vec![("Register A", "729"), ("Register B", "0")])
+        );
+        assert_eq!(key_value(input, program), Ok(("Program", "0,1")));
+        assert_eq!(
+            sections_exact::<3>(input).unwrap_err().to_string(),
+            "line 1, column 1: expected 3 sections, found 2"
+        );
+        assert_eq!(
+            key_value(input, &program[..7]).unwrap_err(),
+            ParseError::new(5, 1, "expected `key: value`, found `Program`")
+        );
+    }
+}
@@ -0,0 +1,468 @@
+use crate::context::{Frame, Sink};
+use crossterm::cursor::{Hide, MoveTo, Show};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::style::{Color, Print, ResetColor, SetForegroundColor};
+use crossterm::terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen};
+use crossterm::{execute, QueueableCommand};
+use std::io::{self, stdout, IsTerminal, Write};
+use std::process;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// The colors of the characters the days draw with, the other characters keep the color of the
+/// terminal.
+const PALETTE: [(char, Color); 13] = [
+    ('#', Color::DarkGrey),
+    ('█', Color::DarkGrey),
+    ('.', Color::Grey),
+    ('*', Color::Green),
+    ('O', Color::Green),
+    ('@', Color::Yellow),
+    ('[', Color::DarkYellow),
+    (']', Color::DarkYellow),
+    ('S', Color::Cyan),
+    ('E', Color::Cyan),
+    ('J', Color::Red),
+    ('(', Color::Red),
+    (')', Color::Red),
+];
+
+const HELP: &str = "space pause, n step, f fast-forward, +/- speed, q stop showing";
+
+/// How long a paused visualizer waits for a key before checking again.
+const PAUSED_POLL: Duration = Duration::from_millis(100);
+
+/// Shows the frames of the solutions in the alternate screen of the terminal, at a steady pace
+/// and with keys to pause, step and fast-forward. The screen is only entered once a day draws
+/// its first frame and is left when the day is done, so the reports are printed as usual.
+pub struct Visualizer {
+    state: Mutex<State>,
+}
+
+struct State {
+    fps: u32,
+    /// The day whose frames are shown, frames of a day that was given up on are dropped.
+    day: Option<usize>,
+    on_screen: bool,
+    stopped: bool,
+    paused: bool,
+    fast_forward: bool,
+    frames: usize,
+    next: Instant,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Key {
+    Pause,
+    Step,
+    FastForward,
+    Faster,
+    Slower,
+    Stop,
+    Interrupt,
+}
+
+impl Visualizer {
+    pub fn new(fps: u32) -> Result<Arc<Visualizer>, String> {
+        if !stdout().is_terminal() {
+            return Err("--visualize needs stdout to be a terminal".to_string());
+        }
+
+        Ok(Arc::new(Visualizer {
+            state: Mutex::new(State::new(fps)),
+        }))
+    }
+
+    /// The sink for the frames of a day.
+    pub fn screen(self: &Arc<Self>, day: usize) -> Box<dyn Sink> {
+        Box::new(Screen {
+            visualizer: Arc::clone(self),
+            day,
+        })
+    }
+
+    /// Starts showing the frames of `day`.
+    pub fn start(&self, day: usize) {
+        let mut state = self.state.lock().unwrap();
+
+        state.day = Some(day);
+        state.frames = 0;
+    }
+
+    /// Leaves the alternate screen, frames that still arrive for the last day are dropped. Unless
+    /// it is fast-forwarding, the last frame stays until a key is pressed.
+    pub fn finish(&self) {
+        let mut state = self.state.lock().unwrap();
+
+        state.day = None;
+
+        if state.on_screen && !state.fast_forward && show_done().is_ok() {
+            // The frames that are dropped meanwhile should not wait for the key
+            drop(state);
+            let key = read_any_key();
+            state = self.state.lock().unwrap();
+
+            if let Some(Some(Key::Interrupt)) = key {
+                self.press(&mut state, Key::Interrupt);
+            }
+        }
+
+        leave(&mut state);
+    }
+
+    fn show(&self, day: usize, frame: &Frame) {
+        let mut state = self.state.lock().unwrap();
+
+        if state.stopped || state.day != Some(day) {
+            return;
+        }
+
+        state.frames += 1;
+
+        if frame.pause {
+            state.paused = true;
+        }
+
+        // Fast-forwarding skips the frames that arrive before the last one was shown long enough
+        if state.fast_forward && !state.paused && Instant::now() < state.next {
+            while let Some(key) = read_key(Duration::ZERO) {
+                self.press(&mut state, key);
+            }
+
+            return;
+        }
+
+        if !state.on_screen {
+            let entered = terminal::enable_raw_mode()
+                .and_then(|_| execute!(stdout(), EnterAlternateScreen, Hide));
+
+            if entered.is_err() {
+                state.stopped = true;
+                return;
+            }
+
+            state.on_screen = true;
+        }
+
+        let shown_at = Instant::now();
+        let mut redraw = true;
+
+        loop {
+            if redraw && draw(day, frame, &state).is_err() {
+                state.stopped = true;
+                leave(&mut state);
+                return;
+            }
+
+            let wait = if state.paused {
+                PAUSED_POLL
+            } else if state.fast_forward {
+                Duration::ZERO
+            } else {
+                (shown_at + state.interval()).saturating_duration_since(Instant::now())
+            };
+
+            if !state.paused && wait.is_zero() {
+                break;
+            }
+
+            // The lock is not held while waiting, so `finish` can give up on the day meanwhile
+            drop(state);
+            let key = read_key(wait);
+            state = self.state.lock().unwrap();
+
+            if state.stopped || state.day != Some(day) {
+                return;
+            }
+
+            // The status line changes with every key, a frame that is waited on is drawn again
+            match key {
+                Some(Key::Step) if state.paused => break,
+                Some(key) => {
+                    self.press(&mut state, key);
+                    redraw = true;
+                }
+                None => redraw = false,
+            }
+
+            if state.stopped {
+                return;
+            }
+        }
+
+        state.next = shown_at + state.interval();
+    }
+
+    fn press(&self, state: &mut State, key: Key) {
+        match key {
+            Key::Stop => {
+                state.stopped = true;
+                leave(state);
+            }
+            Key::Interrupt => {
+                leave(state);
+                process::exit(130);
+            }
+            key => state.press(key),
+        }
+    }
+}
+
+impl Drop for Visualizer {
+    fn drop(&mut self) {
+        leave(&mut self.state.lock().unwrap());
+    }
+}
+
+impl State {
+    fn new(fps: u32) -> State {
+        State {
+            fps,
+            day: None,
+            on_screen: false,
+            stopped: false,
+            paused: false,
+            fast_forward: false,
+            frames: 0,
+            next: Instant::now(),
+        }
+    }
+
+    fn interval(&self) -> Duration {
+        Duration::from_secs(1) / self.fps
+    }
+
+    /// Applies the keys that only change how the frames are paced.
+    fn press(&mut self, key: Key) {
+        match key {
+            Key::Pause => self.paused = !self.paused,
+            Key::Step => self.paused = true,
+            Key::FastForward => self.fast_forward = !self.fast_forward,
+            Key::Faster => self.fps = (self.fps * 2).min(1000),
+            Key::Slower => self.fps = (self.fps / 2).max(1),
+            Key::Stop | Key::Interrupt => {}
+        }
+    }
+
+    fn status(&self, day: usize, frame: &Frame) -> String {
+        let mut status = format!("Day {}", day);
+
+        if let Some(part) = frame.part {
+            status.push_str(&format!(" part {}", part));
+        }
+
+        status.push_str(&format!(
+            ", {}, frame {}, {} fps",
+            frame.caption, self.frames, self.fps
+        ));
+
+        if self.paused {
+            status.push_str(", paused");
+        } else if self.fast_forward {
+            status.push_str(", fast-forward");
+        }
+
+        status
+    }
+}
+
+fn leave(state: &mut State) {
+    if state.on_screen {
+        state.on_screen = false;
+
+        let _ = execute!(stdout(), ResetColor, Show, LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+/// Draws the frame cut to the size of the terminal, with the status and the keys underneath.
+fn draw(day: usize, frame: &Frame, state: &State) -> io::Result<()> {
+    let (cols, rows) = terminal::size()?;
+    let height = rows.saturating_sub(3);
+    let mut out = stdout().lock();
+
+    out.queue(Clear(ClearType::All))?;
+
+    for (y, line) in frame.text.lines().take(height as usize).enumerate() {
+        let line: String = line.chars().take(cols as usize).collect();
+
+        out.queue(MoveTo(0, y as u16))?;
+
+        for (color, run) in color_runs(&line) {
+            match color {
+                Some(color) => out.queue(SetForegroundColor(color))?,
+                None => out.queue(ResetColor)?,
+            };
+            out.queue(Print(run))?;
+        }
+    }
+
+    for (y, text) in [
+        (height + 1, state.status(day, frame)),
+        (height + 2, HELP.into()),
+    ] {
+        let text: String = text.chars().take(cols as usize).collect();
+
+        out.queue(MoveTo(0, y))?
+            .queue(ResetColor)?
+            .queue(Print(text))?;
+    }
+
+    out.flush()
+}
+
+fn color(c: char) -> Option<Color> {
+    PALETTE
+        .iter()
+        .find(|(symbol, _)| *symbol == c)
+        .map(|(_, color)| *color)
+}
+
+/// Splits a line into the longest runs of characters that have the same color.
+fn color_runs(line: &str) -> Vec<(Option<Color>, String)> {
+    let mut runs: Vec<(Option<Color>, String)> = vec![];
+
+    for c in line.chars() {
+        let color = color(c);
+
+        match runs.last_mut() {
+            Some((last, run)) if *last == color => run.push(c),
+            _ => runs.push((color, c.to_string())),
+        }
+    }
+
+    runs
+}
+
+/// Replaces the keys under the last frame of a day.
+fn show_done() -> io::Result<()> {
+    let (_, rows) = terminal::size()?;
+
+    execute!(
+        stdout(),
+        MoveTo(0, rows.saturating_sub(1)),
+        Clear(ClearType::CurrentLine),
+        ResetColor,
+        Print("done, press a key to continue")
+    )
+}
+
+/// Waits for any key press, with the key it is mapped to. Gives up when the terminal fails.
+fn read_any_key() -> Option<Option<Key>> {
+    loop {
+        if let Event::Key(event) = event::read().ok()? {
+            if event.kind == KeyEventKind::Press {
+                return Some(key(event));
+            }
+        }
+    }
+}
+
+/// Waits up to `timeout` for a key press.
+fn read_key(timeout: Duration) -> Option<Key> {
+    if !event::poll(timeout).ok()? {
+        return None;
+    }
+
+    match event::read().ok()? {
+        Event::Key(event) => key(event),
+        _ => None,
+    }
+}
+
+fn key(event: KeyEvent) -> Option<Key> {
+    if event.kind != KeyEventKind::Press {
+        return None;
+    }
+
+    match event.code {
+        KeyCode::Char('c') if event.modifiers.contains(KeyModifiers::CONTROL) => {
+            Some(Key::Interrupt)
+        }
+        KeyCode::Char(' ') | KeyCode::Char('p') => Some(Key::Pause),
+        KeyCode::Char('n') | KeyCode::Right => Some(Key::Step),
+        KeyCode::Char('f') => Some(Key::FastForward),
+        KeyCode::Char('+') | KeyCode::Char('=') | KeyCode::Up => Some(Key::Faster),
+        KeyCode::Char('-') | KeyCode::Down => Some(Key::Slower),
+        KeyCode::Char('q') | KeyCode::Esc => Some(Key::Stop),
+        _ => None,
+    }
+}
+
+struct Screen {
+    visualizer: Arc<Visualizer>,
+    day: usize,
+}
+
+impl Sink for Screen {
+    fn show(&mut self, frame: &Frame) {
+        self.visualizer.show(self.day, frame);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::Part;
+
+    #[test]
+    pub fn test_color_runs() {
+        assert_eq!(
+            color_runs("##.@x"),
+            vec![
+                (Some(Color::DarkGrey), "##".to_string()),
+                (Some(Color::Grey), ".".to_string()),
+                (Some(Color::Yellow), "@".to_string()),
+                (None, "x".to_string()),
+            ]
+        );
+        assert!(color_runs("").is_empty());
+    }
+
+    #[test]
+    pub fn test_keys() {
+        let press = |code, modifiers| key(KeyEvent::new(code, modifiers));
+
+        assert_eq!(
+            press(KeyCode::Char(' '), KeyModifiers::NONE),
+            Some(Key::Pause)
+        );
+        assert_eq!(press(KeyCode::Right, KeyModifiers::NONE), Some(Key::Step));
+        assert_eq!(
+            press(KeyCode::Char('c'), KeyModifiers::CONTROL),
+            Some(Key::Interrupt)
+        );
+        assert_eq!(press(KeyCode::Char('c'), KeyModifiers::NONE), None);
+    }
+
+    #[test]
+    pub fn test_pacing() {
+        let mut state = State::new(30);
+
+        state.press(Key::Faster);
+        assert_eq!(state.fps, 60);
+        state.press(Key::Slower);
+        state.press(Key::Slower);
+        assert_eq!(state.interval(), Duration::from_secs(1) / 15);
+
+        state.press(Key::Step);
+        assert!(state.paused);
+        state.press(Key::Pause);
+        assert!(!state.paused);
+
+        state.press(Key::FastForward);
+        state.frames = 3;
+
+        let frame = Frame {
+            part: Some(Part::Two),
+            caption: "move >".to_string(),
+            text: String::new(),
+            pause: false,
+        };
+
+        assert_eq!(
+            state.status(15, &frame),
+            "Day 15 part 2, move >, frame 3, 15 fps, fast-forward"
+        );
+    }
+}
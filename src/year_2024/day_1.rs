@@ -0,0 +1,77 @@
+use crate::context::Context;
+use crate::solution::{Answer, Solution};
+use crate::utils::parse::{self, ParseError};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Parsed = (Vec<i32>, Vec<i32>);
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        get_sorted_list(input)
+    }
+
+    fn part1(&self, lists: &Self::Parsed, _: &Context) -> Answer {
+        part1(lists).into()
+    }
+
+    fn part2(&self, lists: &Self::Parsed, _: &Context) -> Answer {
+        part2(lists).into()
+    }
+}
+
+fn part1((left_side, right_side): &(Vec<i32>, Vec<i32>)) -> i32 {
+    let mut result: i32 = 0;
+
+    for i in 0..left_side.len() {
+        result += (left_side.get(i).unwrap() - right_side.get(i).unwrap()).abs();
+    }
+
+    result
+}
+
+fn part2((left_side, right_side): &(Vec<i32>, Vec<i32>)) -> i32 {
+    let mut result: i32 = 0;
+
+    for i in 0..left_side.len() {
+        let number_to_find = left_side.get(i).unwrap();
+
+        let records_found = right_side.iter().filter(|&x| x == number_to_find).count();
+
+        result += number_to_find * records_found as i32;
+    }
+
+    result
+}
+
+fn get_sorted_list(input: &str) -> Result<(Vec<i32>, Vec<i32>), ParseError> {
+    let mut left_side: Vec<i32> = Vec::new();
+    let mut right_side: Vec<i32> = Vec::new();
+
+    for [left, right] in parse::lines(input, |line| parse::integers_exact(input, line))? {
+        left_side.push(left);
+        right_side.push(right);
+    }
+
+    left_side.sort();
+    right_side.sort();
+
+    Ok((left_side, right_side))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = include_str!("../../inputs/2024/day_1/test");
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&get_sorted_list(TEST_INPUT).unwrap()), 11);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&get_sorted_list(TEST_INPUT).unwrap()), 31);
+    }
+}
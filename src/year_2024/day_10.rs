@@ -0,0 +1,89 @@
+use crate::context::Context;
+use crate::solution::{Answer, Solution};
+use crate::utils::grid::Grid;
+use crate::utils::movement::{Direction, Point};
+use crate::utils::parse::ParseError;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Parsed = Grid<usize>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, map: &Self::Parsed, _: &Context) -> Answer {
+        part1(map).into()
+    }
+
+    fn part2(&self, map: &Self::Parsed, _: &Context) -> Answer {
+        part2(map).into()
+    }
+}
+
+fn part1(map: &Grid<usize>) -> i32 {
+    do_something(map, true)
+}
+
+fn part2(map: &Grid<usize>) -> i32 {
+    do_something(map, false)
+}
+
+fn parse_input(input: &str) -> Result<Grid<usize>, ParseError> {
+    Grid::parse(input, |c| c.to_digit(10).map(|d| d as usize))
+}
+
+fn do_something(map: &Grid<usize>, unique_only: bool) -> i32 {
+    let mut score = 0;
+
+    for spot in map.find_all(&0) {
+        let mut unexplored_paths: Vec<Point> = next_hiking_spots(map, spot);
+        let mut end_positions: Vec<Point> = vec![];
+
+        while let Some(current_position) = unexplored_paths.pop() {
+            if map[current_position] == 9 {
+                if unique_only {
+                    if !end_positions.contains(&current_position) {
+                        end_positions.push(current_position);
+                        score += 1;
+                    }
+                } else {
+                    score += 1;
+                }
+
+                continue;
+            }
+
+            unexplored_paths.extend(next_hiking_spots(map, current_position));
+        }
+    }
+
+    score
+}
+
+fn next_hiking_spots(map: &Grid<usize>, position: Point) -> Vec<Point> {
+    let current = map[position];
+
+    map.neighbours(position, &Direction::ORTHOGONAL)
+        .filter(|(_, height)| **height == current + 1)
+        .map(|(spot, _)| spot)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = include_str!("../../inputs/2024/day_10/test");
+
+    #[test]
+    pub fn test_part1() {
+        assert_eq!(part1(&parse_input(TEST_INPUT).unwrap()), 36);
+    }
+
+    #[test]
+    pub fn test_part2() {
+        assert_eq!(part2(&parse_input(TEST_INPUT).unwrap()), 81);
+    }
+}
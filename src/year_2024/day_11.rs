@@ -1,18 +1,34 @@
+use crate::context::Context;
+use crate::solution::{Answer, Solution};
+use crate::utils::parse::{self, ParseError};
 use std::collections::HashMap;
 
-pub fn execute(input: &str) {
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Parsed = HashMap<usize, usize>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, number_map: &Self::Parsed, _: &Context) -> Answer {
+        part1(number_map).into()
+    }
+
+    fn part2(&self, number_map: &Self::Parsed, _: &Context) -> Answer {
+        part2(number_map).into()
+    }
 }
 
-fn part1(input: &str) -> usize {
-    let mut number_map = parse_input(input);
+fn part1(number_map: &HashMap<usize, usize>) -> usize {
+    let mut number_map = number_map.clone();
 
     do_the_number_loop(&mut number_map, 25)
 }
 
-fn part2(input: &str) -> usize {
-    let mut number_map = parse_input(input);
+fn part2(number_map: &HashMap<usize, usize>) -> usize {
+    let mut number_map = number_map.clone();
 
     do_the_number_loop(&mut number_map, 75)
 }
@@ -61,22 +77,21 @@ fn do_the_number_loop(map: &mut HashMap<usize, usize>, blinks: usize) -> usize {
     map.iter().fold(0, |acc, (_, v)| acc + *v)
 }
 
-fn parse_input(input: &str) -> HashMap<usize, usize> {
-    input.split_whitespace().fold(HashMap::new(), |mut acc, l| {
-        acc.insert(l.parse::<usize>().unwrap(), 1);
-
-        acc
-    })
+fn parse_input(input: &str) -> Result<HashMap<usize, usize>, ParseError> {
+    Ok(parse::integers(input, input)?
+        .into_iter()
+        .map(|stone| (stone, 1))
+        .collect())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const TEST_INPUT: &str = include_str!("../inputs/day_11/test");
+    const TEST_INPUT: &str = include_str!("../../inputs/2024/day_11/test");
 
     #[test]
     pub fn test_part1() {
-        assert_eq!(part1(TEST_INPUT), 55312);
+        assert_eq!(part1(&parse_input(TEST_INPUT).unwrap()), 55312);
     }
 }
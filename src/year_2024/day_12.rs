@@ -0,0 +1,198 @@
+use crate::context::Context;
+use crate::solution::{Answer, Solution};
+use crate::utils::grid::Grid;
+use crate::utils::movement::{Direction, Point};
+use crate::utils::parse::ParseError;
+use std::collections::HashMap;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Parsed = Grid<usize>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        Ok(create_char_groups(&Grid::parse(input, Some)?))
+    }
+
+    fn part1(&self, groups: &Self::Parsed, _: &Context) -> Answer {
+        part1(groups).into()
+    }
+
+    fn part2(&self, groups: &Self::Parsed, _: &Context) -> Answer {
+        part2(groups).into()
+    }
+}
+
+fn part1(groups: &Grid<usize>) -> usize {
+    calculate_price(groups, count_fences)
+}
+
+fn part2(groups: &Grid<usize>) -> usize {
+    calculate_price(groups, count_fence_edges)
+}
+
+fn calculate_price(
+    groups: &Grid<usize>,
+    calculator: fn(&Grid<usize>) -> HashMap<usize, usize>,
+) -> usize {
+    let plant_count = count_plants(groups);
+    let fence_count = calculator(groups);
+
+    plant_count.iter().fold(0, |acc, (plant, count)| {
+        acc + count * fence_count.get(plant).unwrap_or(&0)
+    })
+}
+
+fn count_plants(plants: &Grid<usize>) -> HashMap<usize, usize> {
+    plants.iter().fold(HashMap::new(), |mut acc, (_, plant)| {
+        *acc.entry(*plant).or_insert(0) += 1;
+        acc
+    })
+}
+
+fn count_fences(plants: &Grid<usize>) -> HashMap<usize, usize> {
+    let mut fences: HashMap<usize, usize> = HashMap::new();
+
+    for (position, plant) in plants.iter() {
+        *fences.entry(*plant).or_insert(0) += get_fence_count(position, plant, plants);
+    }
+
+    fences
+}
+
+fn count_fence_edges(plants: &Grid<usize>) -> HashMap<usize, usize> {
+    // Fence grid with a point on every corner of a plant, usize denotes adjacent plant group id's
+    let mut fence_points: Grid<HashMap<usize, usize>> =
+        Grid::new(plants.width() + 1, plants.height() + 1, HashMap::new());
+    let corners: Vec<Point> = fence_points.points().collect();
+
+    for corner in corners {
+        let mut map: HashMap<usize, usize> = HashMap::new();
+        let top_left = plants.get(corner.step(Direction::UpLeft)).copied();
+        let top_right = plants.get(corner.step(Direction::Up)).copied();
+        let bot_left = plants.get(corner.step(Direction::Left)).copied();
+        let bot_right = plants.get(corner).copied();
+
+        for plant in [top_left, top_right, bot_left, bot_right]
+            .into_iter()
+            .flatten()
+        {
+            *map.entry(plant).or_insert(0usize) += 1;
+        }
+
+        // All 4 adjacent items are equal
+        if top_left.is_some() && top_right.is_some() && bot_left.is_some() && bot_right.is_some() {
+            if top_left == top_right && top_left == bot_left && top_left == bot_right {
+                fence_points[corner] = map;
+
+                continue;
+            }
+        }
+
+        // \ diagonal match
+        if top_left.is_some()
+            && top_left == bot_right
+            && top_left != bot_left
+            && top_left != top_right
+        {
+            let id = top_left.unwrap();
+
+            *map.entry(id).or_insert(0usize) = 5;
+        }
+
+        // / diagonal match
+        if top_right.is_some()
+            && top_right == bot_left
+            && top_right != bot_right
+            && top_right != top_left
+        {
+            let id = top_right.unwrap();
+
+            *map.entry(id).or_insert(0usize) = 5;
+        }
+
+        fence_points[corner] = map;
+    }
+
+    fence_points
+        .iter()
+        .fold(HashMap::new(), |mut acc, (_, cell)| {
+            cell.iter().for_each(|(id, count)| {
+                if *count == 5 {
+                    *acc.entry(*id).or_insert(0) += 2;
+                } else if *count == 1 || *count == 3 {
+                    *acc.entry(*id).or_insert(0) += 1;
+                }
+            });
+            acc
+        })
+}
+
+fn get_fence_count(position: Point, plant: &usize, plants: &Grid<usize>) -> usize {
+    4 - plants
+        .neighbours(position, &Direction::ORTHOGONAL)
+        .filter(|(_, neighbour)| *neighbour == plant)
+        .count()
+}
+
+fn create_char_groups(plants: &Grid<char>) -> Grid<usize> {
+    let mut visited = Grid::new(plants.width(), plants.height(), false);
+    let mut groups = Grid::new(plants.width(), plants.height(), 0);
+
+    let mut current_group_id = 0;
+
+    // Helper function to perform DFS
+    fn depth_first_search(
+        position: Point,
+        grid: &Grid<char>,
+        visited: &mut Grid<bool>,
+        groups: &mut Grid<usize>,
+        current_group_id: usize,
+    ) {
+        visited[position] = true;
+        groups[position] = current_group_id;
+
+        for (next, plant) in grid.neighbours(position, &Direction::ORTHOGONAL) {
+            if !visited[next] && *plant == grid[position] {
+                depth_first_search(next, grid, visited, groups, current_group_id);
+            }
+        }
+    }
+
+    // Iterate through each cell in the grid
+    for position in plants.points() {
+        if !visited[position] {
+            current_group_id += 1;
+            depth_first_search(
+                position,
+                plants,
+                &mut visited,
+                &mut groups,
+                current_group_id,
+            );
+        }
+    }
+
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = include_str!("../../inputs/2024/day_12/test");
+
+    #[test]
+    pub fn test_part1() {
+        let groups = create_char_groups(&Grid::parse(TEST_INPUT, Some).unwrap());
+
+        assert_eq!(part1(&groups), 1930);
+    }
+
+    #[test]
+    pub fn test_part2() {
+        let groups = create_char_groups(&Grid::parse(TEST_INPUT, Some).unwrap());
+
+        assert_eq!(part2(&groups), 1206);
+    }
+}
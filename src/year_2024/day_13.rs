@@ -0,0 +1,152 @@
+use crate::context::Context;
+use crate::solution::{Answer, Solution};
+use crate::utils::parse::{self, ParseError};
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Parsed = Vec<Arcade>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, arcades: &Self::Parsed, _: &Context) -> Answer {
+        part1(arcades).into()
+    }
+
+    fn part2(&self, arcades: &Self::Parsed, _: &Context) -> Answer {
+        part2(arcades).into()
+    }
+}
+
+#[derive(Clone, PartialEq)]
+pub struct Button {
+    x: u64,
+    y: u64,
+    cost: u8,
+}
+
+#[derive(Clone, PartialEq)]
+pub struct Target {
+    x: u64,
+    y: u64,
+}
+
+type Arcade = (Button, Button, Target);
+
+fn part1(arcades: &[Arcade]) -> u64 {
+    calculate(arcades)
+}
+
+fn part2(arcades: &[Arcade]) -> u64 {
+    let adjusted_arcades: Vec<Arcade> = arcades
+        .iter()
+        .map(|(button_a, button_b, target)| {
+            (
+                button_a.clone(),
+                button_b.clone(),
+                Target {
+                    x: target.x + 10000000000000,
+                    y: target.y + 10000000000000,
+                },
+            )
+        })
+        .collect();
+
+    calculate(&adjusted_arcades)
+}
+
+fn calculate(arcades: &[Arcade]) -> u64 {
+    arcades
+        .iter()
+        .map(|arcade| {
+            let button_a = &arcade.0;
+            let button_b = &arcade.1;
+            let target = &arcade.2;
+
+            // Coefficients for equations
+            let ax = button_a.x as i64;
+            let ay = button_a.y as i64;
+            let bx = button_b.x as i64;
+            let by = button_b.y as i64;
+            let px = target.x as i64;
+            let py = target.y as i64;
+
+            // Calculate the denominator (common factor in both equations)
+            let denominator = by * ax - bx * ay;
+
+            if denominator == 0 {
+                return 0; // No solution possible if denominator is zero
+            }
+
+            // Compute `b` (Button B presses) using the primary equation
+            let b_num = py * ax - px * ay;
+            if b_num % denominator != 0 {
+                return 0; // `b` is not an integer, so no solution
+            }
+
+            let b = b_num / denominator;
+
+            if b < 0 {
+                return 0; // Invalid solution (presses can't be negative)
+            }
+
+            // Compute `a` (Button A presses) using the secondary equation
+            let a_num = px - b * bx;
+            if a_num % ax != 0 {
+                return 0; // `a` is not an integer, so no solution
+            }
+
+            let a = a_num / ax;
+
+            if a < 0 {
+                return 0; // Invalid solution (presses can't be negative)
+            }
+
+            // Calculate the total cost for this solution
+            let cost = (a as u64 * button_a.cost as u64) + (b as u64 * button_b.cost as u64);
+            cost
+        })
+        .sum()
+}
+
+fn parse_input(input: &str) -> Result<Vec<Arcade>, ParseError> {
+    parse::sections(input)
+        .into_iter()
+        .map(|section| {
+            let [ax, ay, bx, by, x, y] = parse::integers_exact(input, section)?;
+
+            Ok((
+                Button {
+                    x: ax,
+                    y: ay,
+                    cost: 3,
+                },
+                Button {
+                    x: bx,
+                    y: by,
+                    cost: 1,
+                },
+                Target { x, y },
+            ))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = include_str!("../../inputs/2024/day_13/test");
+
+    #[test]
+    pub fn test_part1() {
+        assert_eq!(part1(&parse_input(TEST_INPUT).unwrap()), 480);
+    }
+
+    // #[test]
+    // pub fn test_part2() {
+    //     assert_eq!(part2(TEST_INPUT), 1206);
+    // }
+}
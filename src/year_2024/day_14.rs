@@ -0,0 +1,149 @@
+use crate::context::Context;
+use crate::solution::{Answer, Solution};
+use crate::utils::movement::{Direction, Point};
+use crate::utils::parse::{self, ParseError};
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Parsed = Vec<Robot>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, robots: &Self::Parsed, _: &Context) -> Answer {
+        part1(robots, 103, 101).into()
+    }
+
+    fn part2(&self, robots: &Self::Parsed, context: &Context) -> Answer {
+        part2(robots, 103, 101, context).into()
+    }
+}
+
+fn part1(robots: &[Robot], rows: usize, cols: usize) -> usize {
+    let mut robots = robots.to_vec();
+
+    move_robots(&mut robots, 100, rows, cols);
+
+    get_safety_factor(&robots, rows, cols)
+}
+
+fn part2(robots: &[Robot], rows: usize, cols: usize, context: &Context) -> usize {
+    let mut robots = robots.to_vec();
+    let mut seconds: usize = 1;
+
+    loop {
+        move_robots(&mut robots, 1, rows, cols);
+
+        if could_be_a_tree(&robots) {
+            context.debug(|| format!("The robots line up after {} seconds", seconds));
+            context.frame("tree", || draw_tree(&robots, rows, cols));
+
+            return seconds;
+        }
+
+        seconds += 1;
+    }
+}
+
+fn could_be_a_tree(robots: &Vec<Robot>) -> bool {
+    for robot in robots.iter() {
+        let mut count = 1; // Start by counting the current robot
+        let mut location = robot.location;
+
+        for _ in 0..10 {
+            location = location.step(Direction::DownLeft);
+            if robots.iter().any(|r| r.location == location) {
+                count += 1;
+            } else {
+                break;
+            }
+        }
+
+        if count == 10 {
+            return true;
+        }
+    }
+
+    false
+}
+
+fn draw_tree(robots: &[Robot], rows: usize, cols: usize) -> String {
+    let mut tree: Vec<Vec<char>> = vec![vec!['.'; cols]; rows];
+
+    robots.iter().for_each(|robot| {
+        let Point { x, y } = robot.location;
+        tree[y as usize][x as usize] = '#'; // Mark robot locations
+    });
+
+    // Construct the tree visualization as a string
+    let mut tree_output = String::new();
+    for row in &tree {
+        tree_output.push_str(&format!("{}\n", row.iter().collect::<String>()));
+    }
+
+    tree_output
+}
+
+#[derive(Debug, Clone)]
+pub struct Robot {
+    location: Point,
+    vector: Point,
+}
+
+fn parse_input(input: &str) -> Result<Vec<Robot>, ParseError> {
+    parse::lines(input, |line| {
+        let [x, y, dx, dy] = parse::integers_exact(input, line)?;
+
+        Ok(Robot {
+            location: Point::new(x, y),
+            vector: Point::new(dx, dy),
+        })
+    })
+}
+
+fn move_robots(robots: &mut Vec<Robot>, steps: isize, rows: usize, cols: usize) {
+    robots.iter_mut().for_each(|robot| {
+        // The area wraps around, so the steps can be taken at once
+        robot.location = (robot.location + robot.vector * steps).wrap(cols, rows);
+    });
+}
+
+fn get_safety_factor(robots: &Vec<Robot>, rows: usize, cols: usize) -> usize {
+    let mut quadrants: [Vec<&Robot>; 4] = Default::default();
+    let (rows, cols) = (rows as isize, cols as isize);
+
+    robots.iter().for_each(|robot| {
+        let Point { x, y } = robot.location;
+
+        if x < cols / 2 && y < rows / 2 {
+            quadrants[0].push(robot);
+        } else if x > cols / 2 && y < rows / 2 {
+            quadrants[1].push(robot);
+        } else if x < cols / 2 && y > rows / 2 {
+            quadrants[2].push(robot);
+        } else if x > cols / 2 && y > rows / 2 {
+            quadrants[3].push(robot);
+        }
+    });
+
+    quadrants[0].len() * quadrants[1].len() * quadrants[2].len() * quadrants[3].len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = include_str!("../../inputs/2024/day_14/test");
+
+    #[test]
+    pub fn test_part1() {
+        assert_eq!(part1(&parse_input(TEST_INPUT).unwrap(), 7, 11), 12);
+    }
+
+    // #[test]
+    // pub fn test_part2() {
+    //     assert_eq!(part2(TEST_INPUT), 1206);
+    // }
+}
@@ -0,0 +1,276 @@
+use std::collections::HashSet;
+
+use crate::context::Context;
+use crate::solution::{Answer, Solution};
+use crate::utils::grid::Grid;
+use crate::utils::movement::{Direction, Point};
+use crate::utils::parse::{self, ParseError};
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Parsed = (Sokoban, Sokoban);
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        Ok((parse_input(input, false)?, parse_input(input, true)?))
+    }
+
+    fn part1(&self, (narrow, _): &Self::Parsed, context: &Context) -> Answer {
+        part1(narrow, context).into()
+    }
+
+    fn part2(&self, (_, wide): &Self::Parsed, context: &Context) -> Answer {
+        part2(wide, context).into()
+    }
+}
+
+fn part1(sokoban: &Sokoban, context: &Context) -> usize {
+    sokoban.clone().run(context)
+}
+
+fn part2(sokoban: &Sokoban, context: &Context) -> usize {
+    sokoban.clone().run(context)
+}
+
+#[derive(Clone, PartialEq, Hash, Eq)]
+struct MovableTile {
+    locations: Vec<Point>,
+}
+
+impl MovableTile {
+    fn can_move(&self, walls: &HashSet<Point>, direction: Direction) -> bool {
+        self.locations
+            .iter()
+            .all(|location| !walls.contains(&location.step(direction)))
+    }
+
+    fn get_next(&self, boxes: &[MovableTile], direction: Direction) -> HashSet<usize> {
+        let mut next_boxes: HashSet<usize> = HashSet::new();
+
+        for location in self.locations.iter() {
+            let next = location.step(direction);
+
+            if let Some(next_box) = boxes.iter().position(|b| b.locations.contains(&next)) {
+                next_boxes.insert(next_box);
+            }
+        }
+
+        next_boxes
+    }
+
+    fn move_to(&mut self, direction: Direction) {
+        self.locations = self
+            .locations
+            .iter()
+            .map(|location| location.step(direction))
+            .collect();
+    }
+}
+
+#[derive(Clone)]
+pub struct Sokoban {
+    player: MovableTile,
+    boxes: Vec<MovableTile>,
+    walls: HashSet<Point>,
+    directions: Vec<Direction>,
+}
+
+impl Sokoban {
+    fn run(&mut self, context: &Context) -> usize {
+        for direction in self.directions.clone() {
+            self.step(direction);
+
+            if context.visualizing() {
+                context.frame(&format!("move {}", direction.arrow()), || self.draw());
+            }
+        }
+
+        self.get_score()
+    }
+
+    fn get_score(&self) -> usize {
+        self.boxes.iter().fold(0, |acc, tile| {
+            let Point { x, y } = tile.locations.first().unwrap();
+
+            acc + ((*y * 100) + *x) as usize
+        })
+    }
+
+    fn step(&mut self, direction: Direction) {
+        let old_boxes = self.boxes.clone();
+        let old_player = self.player.clone();
+
+        let mut blocks_to_process: Vec<usize> = vec![usize::MAX];
+        let mut hit_wall = false;
+        let mut processed: HashSet<usize> = HashSet::new();
+
+        'outer: while !blocks_to_process.is_empty() && !hit_wall {
+            let tile_index = blocks_to_process.pop().unwrap();
+            let tile = if tile_index == usize::MAX {
+                &mut self.player
+            } else {
+                &mut self.boxes[tile_index]
+            };
+
+            processed.insert(tile_index);
+
+            if !tile.can_move(&self.walls, direction) {
+                hit_wall = true;
+                break 'outer;
+            }
+
+            for index in tile.get_next(&old_boxes, direction) {
+                if !blocks_to_process.contains(&index) && !processed.contains(&index) {
+                    blocks_to_process.push(index);
+                }
+            }
+
+            tile.move_to(direction);
+        }
+
+        if hit_wall {
+            self.boxes = old_boxes;
+            self.player = old_player;
+        }
+    }
+
+    fn draw(&self) -> String {
+        let width = self.walls.iter().map(|p| p.x).max().unwrap() + 1;
+        let height = self.walls.iter().map(|p| p.y).max().unwrap() + 1;
+        let mut map = Grid::new(width as usize, height as usize, ' ');
+
+        for wall in self.walls.iter() {
+            map[*wall] = '#';
+        }
+
+        for tile in self.boxes.iter() {
+            if let [location] = tile.locations[..] {
+                map[location] = 'O';
+            } else {
+                map[*tile.locations.last().unwrap()] = ']';
+                map[*tile.locations.first().unwrap()] = '[';
+            }
+        }
+
+        map[self.player.locations[0]] = '@';
+        map.to_string()
+    }
+}
+
+fn parse_input(input: &str, wide: bool) -> Result<Sokoban, ParseError> {
+    let mut player: MovableTile = MovableTile { locations: vec![] };
+    let mut boxes: Vec<MovableTile> = vec![];
+    let mut walls: HashSet<Point> = HashSet::new();
+    let mut directions: Vec<Direction> = vec![];
+    let [warehouse, moves] = parse::sections_exact(input)?;
+
+    for (y, line) in warehouse.lines().enumerate() {
+        let y = y as isize;
+        let mut x = 0;
+
+        for (i, c) in line.char_indices() {
+            if c == '.' {
+                x += if wide { 2 } else { 1 };
+
+                continue;
+            }
+
+            if c == '#' {
+                walls.insert(Point::new(x, y));
+                x += 1;
+
+                if wide {
+                    walls.insert(Point::new(x, y));
+                    x += 1;
+                }
+            } else if c == 'O' {
+                let mut locations = vec![Point::new(x, y)];
+                x += 1;
+                if wide {
+                    locations.push(Point::new(x, y));
+                    x += 1;
+                }
+
+                boxes.push(MovableTile { locations });
+            } else if c == '@' {
+                player = MovableTile {
+                    locations: vec![Point::new(x, y)],
+                };
+
+                x += if wide { 2 } else { 1 };
+            } else {
+                return Err(ParseError::at(
+                    input,
+                    &line[i..],
+                    format!("unexpected `{}` in the warehouse", c),
+                ));
+            }
+        }
+    }
+
+    for line in moves.lines() {
+        for (i, c) in line.char_indices() {
+            let direction = Direction::from_char(c).ok_or_else(|| {
+                ParseError::at(input, &line[i..], format!("unexpected move `{}`", c))
+            })?;
+
+            directions.push(direction);
+        }
+    }
+
+    if player.locations.is_empty() {
+        return Err(ParseError::at(input, input, "the warehouse has no robot"));
+    }
+
+    Ok(Sokoban {
+        player,
+        boxes,
+        walls,
+        directions,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SMALL_TEST_INPUT: &str = include_str!("../../inputs/2024/day_15/small_test");
+    const LARGE_TEST_INPUT: &str = include_str!("../../inputs/2024/day_15/large_test");
+    const ANOTHER_TEST_INPUT: &str = include_str!("../../inputs/2024/day_15/another_test");
+
+    #[test]
+    pub fn test_part1() {
+        assert_eq!(
+            part1(
+                &parse_input(SMALL_TEST_INPUT, false).unwrap(),
+                &Context::silent()
+            ),
+            2028
+        );
+        assert_eq!(
+            part1(
+                &parse_input(LARGE_TEST_INPUT, false).unwrap(),
+                &Context::silent()
+            ),
+            10092
+        );
+    }
+
+    #[test]
+    pub fn test_part2() {
+        assert_eq!(
+            part2(
+                &parse_input(ANOTHER_TEST_INPUT, true).unwrap(),
+                &Context::silent()
+            ),
+            11042
+        );
+        assert_eq!(
+            part2(
+                &parse_input(LARGE_TEST_INPUT, true).unwrap(),
+                &Context::silent()
+            ),
+            9021
+        );
+    }
+}
@@ -0,0 +1,246 @@
+use pathfinding::prelude::dijkstra;
+use std::collections::HashSet;
+
+use crate::context::Context;
+use crate::solution::{Answer, Solution};
+use crate::timing;
+use crate::utils::grid::Grid;
+use crate::utils::movement::{Direction, Point};
+use crate::utils::parse::ParseError;
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Parsed = (Point, Point, Grid<bool>);
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, (start, end, maze): &Self::Parsed, context: &Context) -> Answer {
+        part1(start, end, maze, context).into()
+    }
+
+    fn part2(&self, (start, end, maze): &Self::Parsed, context: &Context) -> Answer {
+        part2(start, end, maze, context).into()
+    }
+}
+
+fn part1(start: &Point, end: &Point, maze: &Grid<bool>, context: &Context) -> usize {
+    let mut maze = maze.clone();
+
+    timing::phase("block dead ends", || block_dead_ends(&mut maze, start, end));
+
+    let (path, score) = timing::phase("shortest path", || {
+        dijkstra(
+            &Path(*start, Direction::Right),
+            |p| p.successors(&maze, None),
+            |p| p.0 == *end,
+        )
+        .unwrap()
+    });
+
+    context.frame("shortest path", || draw_maze(&maze, start, end, &path));
+
+    score
+}
+
+fn do_the_dijkstra_thing(
+    maze: &Grid<bool>,
+    start: &Path,
+    end: &Point,
+    remove: Option<Point>,
+) -> Option<(Vec<Path>, usize)> {
+    dijkstra(start, |p| p.successors(maze, remove), |p| p.0 == *end)
+}
+
+fn part2(start: &Point, end: &Point, maze: &Grid<bool>, context: &Context) -> usize {
+    let mut maze = maze.clone();
+
+    timing::phase("block dead ends", || block_dead_ends(&mut maze, start, end));
+
+    let (path, _score) = timing::phase("shortest path", || {
+        do_the_dijkstra_thing(&maze, &Path(*start, Direction::Right), end, None).unwrap()
+    });
+
+    let mut visited_tiles: HashSet<Point> = path.iter().map(|p| p.0).collect();
+
+    timing::phase("alternative paths", || {
+        for i in 1..path.len() - 1 {
+            let position = path[i];
+            let next_position = path[i + 1];
+            let next_paths: Vec<Path> =
+                position
+                    .successors(&maze, None)
+                    .iter()
+                    .fold(vec![], |mut acc, (s, _)| {
+                        acc.push(*s);
+
+                        acc
+                    });
+
+            if next_paths.len() > 0 {
+                let (_, expected_score) =
+                    do_the_dijkstra_thing(&maze, &position, end, None).unwrap();
+
+                for _ in next_paths {
+                    let mut adjusted_maze = maze.clone();
+                    adjusted_maze[next_position.0] = false;
+
+                    if let Some((next_path_path, next_path_score)) =
+                        do_the_dijkstra_thing(&adjusted_maze, &position, end, Some(next_position.0))
+                    {
+                        if next_path_score <= expected_score {
+                            next_path_path.iter().for_each(|npp| {
+                                visited_tiles.insert(npp.0);
+                            });
+                        }
+                    }
+                }
+            }
+        }
+    });
+
+    context.frame("best seats", || {
+        draw_maze_all(&maze, start, end, &visited_tiles)
+    });
+
+    // Return the count of unique tiles
+    visited_tiles.len()
+}
+
+/// The order in which the successors of a tile are tried.
+const MOVES: [Direction; 4] = [
+    Direction::Up,
+    Direction::Down,
+    Direction::Left,
+    Direction::Right,
+];
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+struct Path(Point, Direction);
+
+impl Path {
+    fn successors(&self, maze: &Grid<bool>, remove: Option<Point>) -> Vec<(Path, usize)> {
+        let &Path(position, dir) = self;
+
+        MOVES
+            .into_iter()
+            .filter(|&next_dir| next_dir != dir.opposite())
+            .map(|next_dir| Path(position.step(next_dir), next_dir))
+            .filter(|p| maze.get(p.0) == Some(&true) && Some(p.0) != remove)
+            .map(|p| (p, if dir != p.1 { 1001 } else { 1 }))
+            .collect()
+    }
+}
+
+fn draw_maze(maze: &Grid<bool>, start: &Point, end: &Point, path: &[Path]) -> String {
+    let mut out = String::new();
+
+    for (position, cell) in maze.iter() {
+        if *start == position {
+            out.push('S');
+        } else if *end == position {
+            out.push('E');
+        } else if path.iter().any(|p| p.0 == position) {
+            out.push('*');
+        } else {
+            out.push(if *cell { '.' } else { '█' });
+        }
+
+        if position.x as usize == maze.width() - 1 {
+            out.push('\n');
+        }
+    }
+
+    out
+}
+
+fn draw_maze_all(maze: &Grid<bool>, start: &Point, end: &Point, path: &HashSet<Point>) -> String {
+    let mut out = String::new();
+
+    for (position, cell) in maze.iter() {
+        if *start == position {
+            out.push('S');
+        } else if *end == position {
+            out.push('E');
+        } else if !*cell {
+            out.push('█');
+        } else if path.contains(&position) {
+            out.push('O');
+        } else {
+            out.push('.');
+        }
+
+        if position.x as usize == maze.width() - 1 {
+            out.push('\n');
+        }
+    }
+
+    out
+}
+
+fn block_dead_ends(maze: &mut Grid<bool>, start: &Point, end: &Point) {
+    let mut has_changes = true;
+    let last_row = maze.height() as isize - 1;
+    let inner: Vec<Point> = maze
+        .points()
+        .filter(|p| p.y != 0 && p.y != last_row)
+        .collect();
+
+    while has_changes {
+        has_changes = false;
+
+        for &position in inner.iter() {
+            if !maze[position] || *start == position || *end == position {
+                continue; // Skip cells that are walls, start, or end
+            }
+
+            // Count paths on the fly, directly using the updated maze
+            if count_paths(maze, position) == 1 {
+                maze[position] = false; // Update the maze immediately
+                has_changes = true;
+            }
+        }
+    }
+}
+
+fn count_paths(maze: &Grid<bool>, position: Point) -> usize {
+    maze.neighbours(position, &Direction::ORTHOGONAL)
+        .filter(|(_, open)| **open)
+        .count()
+}
+
+fn parse_input(input: &str) -> Result<(Point, Point, Grid<bool>), ParseError> {
+    let tiles = Grid::parse(input, |c| "#.SE".contains(c).then_some(c))?;
+    let start = tiles.find(&'S').unwrap_or_default();
+    let end = tiles.find(&'E').unwrap_or_default();
+
+    Ok((start, end, tiles.map(|c| *c != '#')))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SMALL_TEST_INPUT: &str = include_str!("../../inputs/2024/day_16/small_test");
+    const LARGE_TEST_INPUT: &str = include_str!("../../inputs/2024/day_16/large_test");
+
+    #[test]
+    pub fn test_part1() {
+        let (start, end, maze) = parse_input(SMALL_TEST_INPUT).unwrap();
+        assert_eq!(part1(&start, &end, &maze, &Context::silent()), 7036);
+
+        let (start, end, maze) = parse_input(LARGE_TEST_INPUT).unwrap();
+        assert_eq!(part1(&start, &end, &maze, &Context::silent()), 11048);
+    }
+
+    #[test]
+    pub fn test_part2() {
+        let (start, end, maze) = parse_input(SMALL_TEST_INPUT).unwrap();
+        assert_eq!(part2(&start, &end, &maze, &Context::silent()), 45);
+
+        let (start, end, maze) = parse_input(LARGE_TEST_INPUT).unwrap();
+        assert_eq!(part2(&start, &end, &maze, &Context::silent()), 64);
+    }
+}
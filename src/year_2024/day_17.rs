@@ -0,0 +1,1452 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::ops::{BitXor, Div};
+
+use crate::context::{Context, Level};
+use crate::solution::{Answer, Solution};
+use crate::utils::parse::{self, ParseError};
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Parsed = (Vec<usize>, Registers);
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, (instructions, registers): &Self::Parsed, context: &Context) -> Answer {
+        context.debug(|| format!("Disassembled program\n{}", disassemble(instructions)));
+        context.debug(|| format!("Pseudo-code\n{}", pseudo_code(instructions)));
+
+        part1(instructions, registers, context).into()
+    }
+
+    fn part2(&self, (instructions, _): &Self::Parsed, context: &Context) -> Answer {
+        part2(instructions, context).into()
+    }
+}
+
+fn part1(instructions: &[usize], registers: &Registers, context: &Context) -> String {
+    let breakpoints = breakpoints(context);
+    let mut held: Vec<bool> = vec![false; breakpoints.len()];
+
+    let output = run_traced(instructions, registers, 10000, |machine, step| {
+        // A breakpoint is hit when its condition starts to hold, not on every step after that
+        for ((condition, breakpoint), held) in breakpoints.iter().zip(held.iter_mut()) {
+            let holds = breakpoint.holds(&machine.registers);
+
+            if holds && !*held {
+                context.breakpoint(&format!("breakpoint `{}`", condition), || machine.draw());
+            }
+
+            *held = holds;
+        }
+
+        if let (Some(step), true) = (step, context.visualizing()) {
+            context.frame(&step.to_string(), || machine.draw());
+        }
+    });
+
+    join(&output.unwrap_or_else(|e| panic!("The program failed, {}", e)))
+}
+
+fn part2(instructions: &[usize], context: &Context) -> usize {
+    let analysis =
+        analyse(instructions).unwrap_or_else(|e| panic!("Cannot search for a quine, {}", e));
+
+    context.debug(|| analysis.to_string());
+
+    search_quine(instructions, &analysis, 0, 0, 0)
+        .expect("No value of A makes the program output itself")
+}
+
+/// What the quine search needs to know about a program.
+#[derive(Debug, Eq, PartialEq)]
+struct Analysis {
+    /// How many bits A is shifted right by every loop.
+    shift: usize,
+    /// How many values are output every loop.
+    outputs: usize,
+    /// How many of the low bits of A at the start of a loop its outputs depend on.
+    window: usize,
+}
+
+/// How many bits of a value are output, and so how many bits of the registers end up in an output.
+const OUTPUT_BITS: usize = 3;
+
+impl Display for Analysis {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Every loop shifts A by {} bits and outputs {} {}, the outputs depend on the low {} \
+             bits of A",
+            self.shift,
+            self.outputs,
+            if self.outputs == 1 { "value" } else { "values" },
+            self.window
+        )
+    }
+}
+
+/// Checks that a program is a single loop whose outputs only depend on a window of the low bits of
+/// A at the start of the loop, while A is shifted right by the same amount every loop. The outputs
+/// of a loop then only depend on the bits of A from where the shifts of the loops before it got to.
+fn analyse(instructions: &[usize]) -> Result<Analysis, String> {
+    if !instructions.len().is_multiple_of(2) {
+        return Err("the last opcode has no operand".to_string());
+    }
+
+    let program = pairs(instructions)
+        .map(|(ip, opcode, operand)| match INSTRUCTIONS.get(opcode) {
+            Some(instruction) => Ok((ip, *instruction, operand)),
+            None => Err(format!("invalid opcode {} at ip {}", opcode, ip)),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let jumps: Vec<_> = program
+        .iter()
+        .filter(|(_, instruction, _)| *instruction == Instruction::Jnz)
+        .collect();
+
+    match jumps[..] {
+        [(ip, _, 0)] if ip + 2 == instructions.len() => {}
+        _ => return Err("the program is not a single loop that ends with `jnz 0`".to_string()),
+    }
+
+    let mut analysis = Analysis {
+        shift: 0,
+        outputs: 0,
+        window: 0,
+    };
+    // How many bits the values of B and C can have, `None` when they can be as large as A. They
+    // are unknown until the loop writes them.
+    let mut bits: [Option<Option<u32>>; 2] = [None, None];
+
+    for &(ip, instruction, operand) in &program {
+        let read = |register: usize| {
+            bits[register - 1].ok_or_else(|| {
+                format!(
+                    "{} is read at ip {} before the loop writes it, so it carries over between loops",
+                    ["A", "B", "C"][register],
+                    ip
+                )
+            })
+        };
+        let combo = |operand: usize| match operand {
+            0..=3 => Ok(Some(usize::BITS - operand.leading_zeros())),
+            4 => Ok(None),
+            5 | 6 => read(operand - 4),
+            _ => Err(format!("reserved combo operand 7 at ip {}", ip)),
+        };
+
+        match instruction {
+            Instruction::Adv => {
+                combo(operand)?;
+
+                if operand > 3 {
+                    return Err(format!(
+                        "A is shifted by {} at ip {}, the shift has to be the same every loop",
+                        ["A", "B", "C"][operand - 4],
+                        ip
+                    ));
+                }
+
+                analysis.shift += operand;
+            }
+            Instruction::Bxl => {
+                let literal = usize::BITS - operand.leading_zeros();
+
+                bits[0] = Some(read(1)?.map(|b| b.max(literal)));
+            }
+            Instruction::Bst => {
+                combo(operand)?;
+
+                if operand == 4 {
+                    analysis.window = analysis.window.max(analysis.shift + OUTPUT_BITS);
+                }
+
+                bits[0] = Some(Some(OUTPUT_BITS as u32));
+            }
+            Instruction::Jnz => {}
+            Instruction::Bxc => {
+                bits[0] = Some(read(1)?.zip(read(2)?).map(|(b, c)| b.max(c)));
+            }
+            Instruction::Out => {
+                combo(operand)?;
+
+                if operand == 4 {
+                    analysis.window = analysis.window.max(analysis.shift + OUTPUT_BITS);
+                }
+
+                analysis.outputs += 1;
+            }
+            Instruction::Bdv | Instruction::Cdv => {
+                let register = if instruction == Instruction::Bdv {
+                    0
+                } else {
+                    1
+                };
+                let bound = combo(operand)?.ok_or_else(|| {
+                    format!(
+                        "{} is A shifted by A at ip {}, so the outputs can depend on every bit of A",
+                        ["B", "C"][register],
+                        ip
+                    )
+                })?;
+                // The output bits of A shifted by at most `max_shift` depend on that many more bits.
+                // The register can then be as large as A, so a shift by it fails the check above
+                let max_shift = (1usize << bound) - 1;
+
+                analysis.window = analysis
+                    .window
+                    .max(analysis.shift + max_shift + OUTPUT_BITS);
+                bits[register] = Some(None);
+            }
+        }
+    }
+
+    let loops = instructions.len() / analysis.outputs.max(1);
+
+    if analysis.shift == 0 {
+        Err("A is never shifted, so the program only halts when A is 0".to_string())
+    } else if analysis.outputs == 0 {
+        Err("the program does not output anything".to_string())
+    } else if !instructions.len().is_multiple_of(analysis.outputs) {
+        Err(format!(
+            "the program outputs {} values every loop, that never adds up to its {} values",
+            analysis.outputs,
+            instructions.len()
+        ))
+    } else if loops * analysis.shift > usize::BITS as usize {
+        Err(format!(
+            "A would need {} bits, more than fit in a register",
+            loops * analysis.shift
+        ))
+    } else {
+        Ok(analysis)
+    }
+}
+
+/// Finds the lowest A that makes the program output itself, backtracking over its bits from low
+/// to high. The outputs of a loop only depend on the `window` bits of A from where the loops
+/// before it shifted to, so every loop picks the bits of its window that the loops before it left
+/// unknown, and a choice is kept when that one loop outputs its part of the program. `known` bits
+/// of `a` are picked so far.
+fn search_quine(
+    instructions: &[usize],
+    analysis: &Analysis,
+    a: usize,
+    known: usize,
+    level: usize,
+) -> Option<usize> {
+    let loops = instructions.len() / analysis.outputs;
+    let bits = loops * analysis.shift;
+
+    // The whole program also has to halt after the last loop, which A being 0 too early breaks
+    if level == loops {
+        let output = run_program(
+            instructions,
+            &[a, 0, 0, 0],
+            (loops + 1) * instructions.len(),
+        );
+
+        return (output.as_deref() == Ok(instructions)).then_some(a);
+    }
+
+    let start = level * analysis.shift;
+    // The bits above the loops stay 0, the windows of the last loops read them as such
+    let needed = (start + analysis.window.max(analysis.shift)).min(bits);
+    let expected = &instructions[level * analysis.outputs..(level + 1) * analysis.outputs];
+
+    (0..1usize << (needed - known))
+        .filter_map(|high| {
+            let candidate = a | high << known;
+
+            match run_loop(instructions, candidate >> start) {
+                Ok(output) if output == expected => {
+                    search_quine(instructions, analysis, candidate, needed, level + 1)
+                }
+                _ => None,
+            }
+        })
+        .min()
+}
+
+/// Runs the loop of a program once, starting with `a` in A, and returns what it output.
+fn run_loop(instructions: &[usize], a: usize) -> Result<Vec<usize>, VmError> {
+    let mut machine = Machine::new(instructions, &[a, 0, 0, 0]);
+
+    for _ in 0..instructions.len() / 2 {
+        machine.step()?;
+    }
+
+    Ok(machine.output)
+}
+
+fn run_program(
+    instructions: &[usize],
+    initial_registers: &Registers,
+    limit: usize,
+) -> Result<Vec<usize>, VmError> {
+    run_traced(instructions, initial_registers, limit, |_, _| {})
+}
+
+/// Runs a program to the end like `run_program`, handing the machine to `observe` before every
+/// step and after the last one, with the step that led to it.
+fn run_traced(
+    instructions: &[usize],
+    initial_registers: &Registers,
+    limit: usize,
+    mut observe: impl FnMut(&Machine, Option<&Step>),
+) -> Result<Vec<usize>, VmError> {
+    let mut machine = Machine::new(instructions, initial_registers);
+
+    observe(&machine, None);
+
+    while let Some(step) = machine.step()? {
+        observe(&machine, Some(&step));
+
+        if machine.steps >= limit && !machine.halted() {
+            return Err(VmError::StepLimit(limit));
+        }
+    }
+
+    Ok(machine.output)
+}
+
+/// Why a program stopped before it halted.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum VmError {
+    StepLimit(usize),
+    InvalidOpcode { ip: usize, opcode: usize },
+    MissingOperand { ip: usize },
+    ReservedOperand { ip: usize },
+    ShiftOverflow { ip: usize, shift: usize },
+}
+
+impl Display for VmError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VmError::StepLimit(limit) => write!(f, "did not halt within {} steps", limit),
+            VmError::InvalidOpcode { ip, opcode } => {
+                write!(f, "invalid opcode {} at ip {}", opcode, ip)
+            }
+            VmError::MissingOperand { ip } => write!(f, "the opcode at ip {} has no operand", ip),
+            VmError::ReservedOperand { ip } => write!(f, "reserved combo operand 7 at ip {}", ip),
+            VmError::ShiftOverflow { ip, shift } => {
+                write!(f, "shift by {} at ip {} overflows", shift, ip)
+            }
+        }
+    }
+}
+
+/// The state of a running program, one instruction at a time.
+struct Machine<'a> {
+    instructions: &'a [usize],
+    registers: Registers,
+    output: Vec<usize>,
+    steps: usize,
+}
+
+/// What a single instruction did. The registers include the instruction pointer.
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct Step {
+    ip: usize,
+    instruction: Instruction,
+    operand: usize,
+    before: Registers,
+    after: Registers,
+    output: Option<usize>,
+}
+
+impl<'a> Machine<'a> {
+    fn new(instructions: &'a [usize], registers: &Registers) -> Machine<'a> {
+        Machine {
+            instructions,
+            registers: *registers,
+            output: vec![],
+            steps: 0,
+        }
+    }
+
+    fn halted(&self) -> bool {
+        self.registers[3] >= self.instructions.len()
+    }
+
+    /// Runs the next instruction, `None` once the program has halted.
+    fn step(&mut self) -> Result<Option<Step>, VmError> {
+        if self.halted() {
+            return Ok(None);
+        }
+
+        let ip = self.registers[3];
+        let opcode = self.instructions[ip];
+        let instruction = *INSTRUCTIONS
+            .get(opcode)
+            .ok_or(VmError::InvalidOpcode { ip, opcode })?;
+        let operand = *self
+            .instructions
+            .get(ip + 1)
+            .ok_or(VmError::MissingOperand { ip })?;
+        let before = self.registers;
+        let written = self.output.len();
+
+        instruction.run(&mut self.registers, operand, &mut self.output)?;
+        self.steps += 1;
+
+        Ok(Some(Step {
+            ip,
+            instruction,
+            operand,
+            before,
+            after: self.registers,
+            output: self.output.get(written).copied(),
+        }))
+    }
+
+    /// The listing of the program with the next instruction marked, the registers and the output.
+    fn draw(&self) -> String {
+        let mut out = String::new();
+
+        let listing = disassemble(self.instructions);
+
+        for (line, (address, _, _)) in listing.lines().zip(pairs(self.instructions)) {
+            let marker = if address == self.registers[3] {
+                '>'
+            } else {
+                ' '
+            };
+
+            out.push_str(&format!("{}{}\n", marker, line));
+        }
+
+        let [a, b, c, _] = self.registers;
+
+        out.push_str(&format!("\nA = {}, B = {}, C = {}\n", a, b, c));
+        out.push_str(&format!("output: {}\n", join(&self.output)));
+        out
+    }
+}
+
+impl Display for Step {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let operand = self
+            .instruction
+            .operand(self.operand)
+            .map(|o| format!(" {}", o))
+            .unwrap_or_default();
+
+        write!(
+            f,
+            "ip {}: {}{}, {}",
+            self.ip,
+            self.instruction.mnemonic(),
+            operand,
+            self.instruction.statement(self.operand)
+        )?;
+
+        for (name, (before, after)) in ["A", "B", "C", "ip"]
+            .iter()
+            .zip(self.before.iter().zip(self.after.iter()))
+        {
+            if before != after {
+                write!(f, ", {} {} -> {}", name, before, after)?;
+            }
+        }
+
+        if let Some(value) = self.output {
+            write!(f, ", out {}", value)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A condition that stops the program, like `ip == 4` or `A < 8`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct Breakpoint {
+    /// The index of the register, 3 is the instruction pointer.
+    register: usize,
+    comparison: Comparison,
+    value: usize,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Comparison {
+    Equal,
+    NotEqual,
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+}
+
+const COMPARISONS: [(&str, Comparison); 6] = [
+    ("==", Comparison::Equal),
+    ("!=", Comparison::NotEqual),
+    ("<=", Comparison::LessOrEqual),
+    (">=", Comparison::GreaterOrEqual),
+    ("<", Comparison::Less),
+    (">", Comparison::Greater),
+];
+
+impl Breakpoint {
+    fn parse(condition: &str) -> Result<Breakpoint, String> {
+        let (index, symbol, comparison) = COMPARISONS
+            .iter()
+            .filter_map(|(symbol, comparison)| {
+                condition
+                    .find(symbol)
+                    .map(|index| (index, *symbol, *comparison))
+            })
+            .min_by_key(|(index, _, _)| *index)
+            .ok_or_else(|| "expected a comparison like `A == 0`".to_string())?;
+        let (name, value) = (
+            condition[..index].trim(),
+            condition[index + symbol.len()..].trim(),
+        );
+        let register = match name {
+            "A" | "a" => 0,
+            "B" | "b" => 1,
+            "C" | "c" => 2,
+            "ip" | "IP" => 3,
+            _ => return Err(format!("unknown register `{}`", name)),
+        };
+        let value = value
+            .parse()
+            .map_err(|_| format!("expected a number, found `{}`", value))?;
+
+        Ok(Breakpoint {
+            register,
+            comparison,
+            value,
+        })
+    }
+
+    fn holds(&self, registers: &Registers) -> bool {
+        let register = registers[self.register];
+
+        match self.comparison {
+            Comparison::Equal => register == self.value,
+            Comparison::NotEqual => register != self.value,
+            Comparison::Less => register < self.value,
+            Comparison::LessOrEqual => register <= self.value,
+            Comparison::Greater => register > self.value,
+            Comparison::GreaterOrEqual => register >= self.value,
+        }
+    }
+}
+
+/// The breakpoints passed with `--break`, the ones that are not understood are skipped with a
+/// warning.
+fn breakpoints(context: &Context) -> Vec<(&str, Breakpoint)> {
+    context
+        .breakpoints()
+        .iter()
+        .filter_map(|condition| match Breakpoint::parse(condition) {
+            Ok(breakpoint) => Some((condition.as_str(), breakpoint)),
+            Err(e) => {
+                context.log(Level::Warn, || {
+                    format!("Skipping the breakpoint `{}`, {}", condition, e)
+                });
+                None
+            }
+        })
+        .collect()
+}
+
+fn join(values: &[usize]) -> String {
+    values
+        .iter()
+        .map(|n| n.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+enum Instruction {
+    Adv,
+    Bxl,
+    Bst,
+    Jnz,
+    Bxc,
+    Out,
+    Bdv,
+    Cdv,
+}
+
+type Registers = [usize; 4];
+
+impl Instruction {
+    fn run(
+        &self,
+        registers: &mut Registers,
+        operand: usize,
+        output: &mut Vec<usize>,
+    ) -> Result<(), VmError> {
+        match self {
+            Instruction::Adv => {
+                registers[0] = divide(registers, operand)?;
+                registers[3] += 2;
+
+                Ok(())
+            }
+            Instruction::Bxl => {
+                registers[1] = registers[1].bitxor(operand);
+                registers[3] += 2;
+
+                Ok(())
+            }
+            Instruction::Bst => {
+                registers[1] = get_operand_value(registers, operand)? % 8;
+                registers[3] += 2;
+
+                Ok(())
+            }
+            Instruction::Jnz => {
+                if registers[0] != 0 {
+                    registers[3] = operand
+                } else {
+                    registers[3] += 2;
+                }
+
+                Ok(())
+            }
+            Instruction::Bxc => {
+                registers[1] = registers[1].bitxor(registers[2]);
+                registers[3] += 2;
+
+                Ok(())
+            }
+            Instruction::Out => {
+                output.push(get_operand_value(registers, operand)? % 8);
+                registers[3] += 2;
+
+                Ok(())
+            }
+            Instruction::Bdv => {
+                registers[1] = divide(registers, operand)?;
+                registers[3] += 2;
+
+                Ok(())
+            }
+            Instruction::Cdv => {
+                registers[2] = divide(registers, operand)?;
+                registers[3] += 2;
+
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Divides A by 2 to the power of the combo operand, like `adv`, `bdv` and `cdv` do.
+fn divide(registers: &Registers, operand: usize) -> Result<usize, VmError> {
+    let shift = get_operand_value(registers, operand)?;
+    let divisor = u32::try_from(shift)
+        .ok()
+        .and_then(|shift| 2_usize.checked_pow(shift))
+        .ok_or(VmError::ShiftOverflow {
+            ip: registers[3],
+            shift,
+        })?;
+
+    Ok(registers[0].div(divisor))
+}
+
+fn get_operand_value(registers: &Registers, operand: usize) -> Result<usize, VmError> {
+    match operand {
+        4 => Ok(registers[0]),
+        5 => Ok(registers[1]),
+        6 => Ok(registers[2]),
+        7 => Err(VmError::ReservedOperand { ip: registers[3] }),
+        _ => Ok(operand),
+    }
+}
+
+const INSTRUCTIONS: [Instruction; 8] = [
+    Instruction::Adv,
+    Instruction::Bxl,
+    Instruction::Bst,
+    Instruction::Jnz,
+    Instruction::Bxc,
+    Instruction::Out,
+    Instruction::Bdv,
+    Instruction::Cdv,
+];
+
+/// An operand with the way the instruction reads it.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Operand {
+    Literal(usize),
+    Combo(usize),
+}
+
+impl Operand {
+    /// The value the operand stands for, like `A` for the combo operand 4.
+    fn resolved(self) -> String {
+        match self {
+            Operand::Literal(value) | Operand::Combo(value @ 0..=3) => value.to_string(),
+            Operand::Combo(4) => "A".to_string(),
+            Operand::Combo(5) => "B".to_string(),
+            Operand::Combo(6) => "C".to_string(),
+            Operand::Combo(_) => "invalid".to_string(),
+        }
+    }
+}
+
+impl Display for Operand {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Operand::Literal(value) => write!(f, "{}", value),
+            Operand::Combo(value) => write!(f, "combo({})={}", value, self.resolved()),
+        }
+    }
+}
+
+impl Instruction {
+    fn mnemonic(&self) -> &'static str {
+        match self {
+            Instruction::Adv => "adv",
+            Instruction::Bxl => "bxl",
+            Instruction::Bst => "bst",
+            Instruction::Jnz => "jnz",
+            Instruction::Bxc => "bxc",
+            Instruction::Out => "out",
+            Instruction::Bdv => "bdv",
+            Instruction::Cdv => "cdv",
+        }
+    }
+
+    /// How the instruction reads its operand, `bxc` ignores it.
+    fn operand(&self, operand: usize) -> Option<Operand> {
+        match self {
+            Instruction::Bxl | Instruction::Jnz => Some(Operand::Literal(operand)),
+            Instruction::Bxc => None,
+            _ => Some(Operand::Combo(operand)),
+        }
+    }
+
+    /// What the instruction does as a statement, like `B = A % 8`.
+    fn statement(&self, operand: usize) -> String {
+        let value = self
+            .operand(operand)
+            .map(Operand::resolved)
+            .unwrap_or_default();
+
+        match self {
+            Instruction::Adv => format!("A = A >> {}", value),
+            Instruction::Bxl => format!("B = B ^ {}", value),
+            Instruction::Bst => format!("B = {} % 8", value),
+            Instruction::Jnz => format!("if A != 0 goto {}", value),
+            Instruction::Bxc => "B = B ^ C".to_string(),
+            Instruction::Out => format!("out {} % 8", value),
+            Instruction::Bdv => format!("B = A >> {}", value),
+            Instruction::Cdv => format!("C = A >> {}", value),
+        }
+    }
+}
+
+/// The opcodes and operands of a program, every pair with its address. A trailing opcode without
+/// an operand is left out, like the machine halts before it.
+fn pairs(program: &[usize]) -> impl Iterator<Item = (usize, usize, usize)> + '_ {
+    program
+        .chunks_exact(2)
+        .enumerate()
+        .map(|(i, pair)| (i * 2, pair[0], pair[1]))
+}
+
+/// Renders a program as one mnemonic per line, with its operand resolved and what it does.
+fn disassemble(program: &[usize]) -> String {
+    let mut listing = String::new();
+
+    for (address, opcode, operand) in pairs(program) {
+        let line = match INSTRUCTIONS.get(opcode) {
+            Some(instruction) => {
+                // `bxc` ignores its operand, it is only shown when it is not 0 so that the
+                // listing assembles back to the same program
+                let resolved = match instruction.operand(operand) {
+                    Some(operand) => operand.to_string(),
+                    None if operand != 0 => operand.to_string(),
+                    None => String::new(),
+                };
+
+                format!(
+                    "{:>3}: {} {:<12} ; {}",
+                    address,
+                    instruction.mnemonic(),
+                    resolved,
+                    instruction.statement(operand)
+                )
+            }
+            None => format!(
+                "{:>3}: ??? {:<12} ; invalid opcode {}",
+                address, operand, opcode
+            ),
+        };
+
+        listing.push_str(line.trim_end());
+        listing.push('\n');
+    }
+
+    listing
+}
+
+/// Renders a program as pseudo-code. The usual program is a single loop that jumps back to the
+/// start at its end, it becomes a `do { } while A != 0` loop. Other jumps are kept as gotos to
+/// labeled lines.
+fn pseudo_code(program: &[usize]) -> String {
+    let instructions: Vec<(usize, Option<Instruction>, usize)> = pairs(program)
+        .map(|(address, opcode, operand)| (address, INSTRUCTIONS.get(opcode).copied(), operand))
+        .collect();
+    let jumps: Vec<(usize, usize)> = instructions
+        .iter()
+        .filter(|(_, instruction, _)| *instruction == Some(Instruction::Jnz))
+        .map(|(address, _, target)| (*address, *target))
+        .collect();
+    let last = instructions.last().map(|(address, _, _)| *address);
+    let single_loop = matches!(jumps[..], [(address, 0)] if Some(address) == last);
+
+    let mut code = String::new();
+    let indent = if single_loop { "    " } else { "" };
+
+    if single_loop {
+        code.push_str("do {\n");
+    }
+
+    for (address, instruction, operand) in instructions {
+        if !single_loop && jumps.iter().any(|(_, target)| *target == address) {
+            code.push_str(&format!("{}:\n", address));
+        }
+
+        let statement = match instruction {
+            Some(Instruction::Jnz) if single_loop => continue,
+            Some(instruction) => instruction.statement(operand),
+            None => "invalid".to_string(),
+        };
+
+        code.push_str(&format!("{}{}\n", indent, statement));
+    }
+
+    if single_loop {
+        code.push_str("} while A != 0\n");
+    }
+
+    code
+}
+
+/// Assembles mnemonic source into the input that `parse_input` reads. The source has one
+/// instruction per line like the disassembly, comments start with `;` or `#`, `name:` labels an
+/// address for `jnz` and `A = 729` sets a register. A numeric label like `12:` checks that the next
+/// instruction is at that address, so the disassembly assembles as is.
+pub fn assemble(source: &str) -> Result<String, ParseError> {
+    let (program, registers) = assemble_program(source)?;
+
+    Ok(format!(
+        "Register A: {}\nRegister B: {}\nRegister C: {}\n\nProgram: {}\n",
+        registers[0],
+        registers[1],
+        registers[2],
+        join(&program)
+    ))
+}
+
+/// Turns an input into source that assembles back to the same input.
+pub fn disassemble_input(input: &str) -> Result<String, ParseError> {
+    let (program, registers) = parse_input(input)?;
+
+    Ok(format!(
+        "A = {}\nB = {}\nC = {}\n\n{}",
+        registers[0],
+        registers[1],
+        registers[2],
+        disassemble(&program)
+    ))
+}
+
+fn assemble_program(source: &str) -> Result<(Vec<usize>, Registers), ParseError> {
+    let mut registers: Registers = [0; 4];
+    let mut labels: HashMap<&str, usize> = HashMap::new();
+    let mut statements: Vec<(Instruction, &str, Option<&str>)> = vec![];
+
+    for line in source.lines() {
+        let mut code = line.split([';', '#']).next().unwrap_or_default().trim();
+
+        while let Some((label, rest)) = code.split_once(':') {
+            let label = label.trim();
+            let address = statements.len() * 2;
+
+            if let Ok(expected) = label.parse::<usize>() {
+                if expected != address {
+                    return Err(ParseError::at(
+                        source,
+                        label,
+                        format!(
+                            "this instruction is at address {}, not {}",
+                            address, expected
+                        ),
+                    ));
+                }
+            } else if !is_label(label) {
+                return Err(ParseError::at(
+                    source,
+                    label,
+                    format!("expected a label, found `{}`", label),
+                ));
+            } else if labels.insert(label, address).is_some() {
+                return Err(ParseError::at(
+                    source,
+                    label,
+                    format!("the label `{}` is defined twice", label),
+                ));
+            }
+
+            code = rest.trim();
+        }
+
+        if code.is_empty() {
+            continue;
+        }
+
+        if let Some((name, value)) = code.split_once('=') {
+            let register = match name.trim() {
+                "A" => Some(0),
+                "B" => Some(1),
+                "C" => Some(2),
+                _ => None,
+            };
+
+            // Anything else with a `=` is an instruction with an operand like `combo(4)=A`
+            if let Some(register) = register {
+                registers[register] = parse::number(source, value.trim())?;
+                continue;
+            }
+        }
+
+        let mut words = code.split_whitespace();
+        let mnemonic = words.next().unwrap_or(code);
+        let operand = words.next();
+
+        if let Some(extra) = words.next() {
+            return Err(ParseError::at(
+                source,
+                extra,
+                format!("unexpected `{}` after the operand", extra),
+            ));
+        }
+
+        let instruction = INSTRUCTIONS
+            .into_iter()
+            .find(|instruction| instruction.mnemonic() == mnemonic)
+            .ok_or_else(|| {
+                ParseError::at(
+                    source,
+                    mnemonic,
+                    format!("unknown instruction `{}`", mnemonic),
+                )
+            })?;
+
+        statements.push((instruction, mnemonic, operand));
+    }
+
+    let mut program: Vec<usize> = Vec::with_capacity(statements.len() * 2);
+
+    for (instruction, mnemonic, operand) in statements {
+        let operand = match (instruction, operand) {
+            (Instruction::Bxc, None) => 0,
+            (Instruction::Bxc | Instruction::Bxl, Some(operand)) => literal(source, operand)?,
+            (Instruction::Jnz, Some(operand)) => match labels.get(operand) {
+                Some(&address) if address > 7 => {
+                    return Err(ParseError::at(
+                        source,
+                        operand,
+                        format!(
+                            "the label `{}` is at address {}, `jnz` can only jump up to 7",
+                            operand, address
+                        ),
+                    ))
+                }
+                Some(&address) => address,
+                None if is_label(operand) => {
+                    return Err(ParseError::at(
+                        source,
+                        operand,
+                        format!("undefined label `{}`", operand),
+                    ))
+                }
+                None => literal(source, operand)?,
+            },
+            (_, Some(operand)) => combo(source, operand)?,
+            (_, None) => {
+                return Err(ParseError::at(
+                    source,
+                    mnemonic,
+                    format!("`{}` needs an operand", mnemonic),
+                ))
+            }
+        };
+
+        program.extend([
+            INSTRUCTIONS.iter().position(|i| *i == instruction).unwrap(),
+            operand,
+        ]);
+    }
+
+    Ok((program, registers))
+}
+
+fn is_label(text: &str) -> bool {
+    text.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && text.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn literal(source: &str, text: &str) -> Result<usize, ParseError> {
+    match text.parse() {
+        Ok(value @ 0..=7) => Ok(value),
+        _ => Err(ParseError::at(
+            source,
+            text,
+            format!("expected a literal operand from 0 to 7, found `{}`", text),
+        )),
+    }
+}
+
+/// A combo operand, written as `0` to `3`, a register or like the disassembly shows it.
+fn combo(source: &str, text: &str) -> Result<usize, ParseError> {
+    let value = match text {
+        "A" => Some(4),
+        "B" => Some(5),
+        "C" => Some(6),
+        _ => match text
+            .strip_prefix("combo(")
+            .and_then(|rest| rest.split_once(')'))
+        {
+            Some((value, _)) => value.parse().ok().filter(|value| *value <= 7),
+            None => text.parse().ok().filter(|value| *value <= 3),
+        },
+    };
+
+    value.ok_or_else(|| {
+        ParseError::at(
+            source,
+            text,
+            format!(
+                "expected a combo operand, 0 to 3, A, B or C, found `{}`",
+                text
+            ),
+        )
+    })
+}
+
+fn parse_input(input: &str) -> Result<(Vec<usize>, Registers), ParseError> {
+    let [register_lines, program] = parse::sections_exact(input)?;
+    let mut registers: Registers = [0; 4];
+
+    for line in register_lines.lines() {
+        let (name, value) = parse::key_value(input, line)?;
+        let register = match name {
+            "Register A" => 0,
+            "Register B" => 1,
+            "Register C" => 2,
+            _ => {
+                return Err(ParseError::at(
+                    input,
+                    name,
+                    format!("unknown register `{}`", name),
+                ))
+            }
+        };
+
+        registers[register] = parse::number(input, value)?;
+    }
+
+    let (name, instructions) = parse::key_value(input, program)?;
+
+    if name != "Program" {
+        return Err(ParseError::at(input, name, "expected `Program`"));
+    }
+
+    Ok((parse::list(input, instructions, ",")?, registers))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = include_str!("../../inputs/2024/day_17/test");
+    const P2_TEST_INPUT: &str = include_str!("../../inputs/2024/day_17/p2_test");
+
+    /// Every step of a run, with the error that ended it if there was one.
+    fn trace(
+        instructions: &[usize],
+        registers: &Registers,
+        limit: usize,
+    ) -> (Vec<Step>, Result<Vec<usize>, VmError>) {
+        let mut steps = vec![];
+        let result = run_traced(instructions, registers, limit, |_, step| {
+            steps.extend(step.cloned());
+        });
+
+        (steps, result)
+    }
+
+    #[test]
+    pub fn test_part1() {
+        let (instructions, registers) = parse_input(TEST_INPUT).unwrap();
+
+        assert_eq!(
+            part1(&instructions, &registers, &Context::silent()),
+            "4,6,3,5,6,3,5,2,1,0"
+        );
+    }
+
+    #[test]
+    pub fn test_disassemble() {
+        let program = [2, 4, 1, 1, 7, 5, 1, 5, 4, 0, 5, 5, 0, 3, 3, 0];
+
+        assert_eq!(
+            disassemble(&program).lines().collect::<Vec<_>>(),
+            vec![
+                "  0: bst combo(4)=A   ; B = A % 8",
+                "  2: bxl 1            ; B = B ^ 1",
+                "  4: cdv combo(5)=B   ; C = A >> B",
+                "  6: bxl 5            ; B = B ^ 5",
+                "  8: bxc              ; B = B ^ C",
+                " 10: out combo(5)=B   ; out B % 8",
+                " 12: adv combo(3)=3   ; A = A >> 3",
+                " 14: jnz 0            ; if A != 0 goto 0",
+            ]
+        );
+        assert_eq!(
+            pseudo_code(&program).lines().collect::<Vec<_>>(),
+            vec![
+                "do {",
+                "    B = A % 8",
+                "    B = B ^ 1",
+                "    C = A >> B",
+                "    B = B ^ 5",
+                "    B = B ^ C",
+                "    out B % 8",
+                "    A = A >> 3",
+                "} while A != 0",
+            ]
+        );
+    }
+
+    #[test]
+    pub fn test_disassemble_odd_programs() {
+        assert_eq!(
+            disassemble(&[9, 1, 2, 7, 0]),
+            "  0: ??? 1            ; invalid opcode 9\n  2: bst combo(7)=invalid ; B = invalid % 8\n"
+        );
+        assert_eq!(
+            pseudo_code(&[3, 4, 5, 4, 3, 0]),
+            "0:\nif A != 0 goto 4\nout A % 8\n4:\nif A != 0 goto 0\n"
+        );
+    }
+
+    #[test]
+    pub fn test_trace() {
+        let (instructions, registers) = parse_input(TEST_INPUT).unwrap();
+        let (steps, output) = trace(&instructions, &registers, 10000);
+
+        assert_eq!(steps.len(), 30);
+        assert_eq!(
+            steps[0],
+            Step {
+                ip: 0,
+                instruction: Instruction::Adv,
+                operand: 1,
+                before: [729, 0, 0, 0],
+                after: [364, 0, 0, 2],
+                output: None,
+            }
+        );
+        assert_eq!(
+            steps[0].to_string(),
+            "ip 0: adv combo(1)=1, A = A >> 1, A 729 -> 364, ip 0 -> 2"
+        );
+        assert_eq!(
+            steps[1].to_string(),
+            "ip 2: out combo(4)=A, out A % 8, ip 2 -> 4, out 4"
+        );
+        assert_eq!(output.unwrap().len(), 10);
+    }
+
+    #[test]
+    pub fn test_errors() {
+        let registers = [8, 0, 0, 0];
+
+        assert_eq!(
+            run_program(&[3, 0], &registers, 100),
+            Err(VmError::StepLimit(100))
+        );
+        assert_eq!(
+            run_program(&[5, 4, 8, 0], &registers, 100),
+            Err(VmError::InvalidOpcode { ip: 2, opcode: 8 })
+        );
+        assert_eq!(
+            run_program(&[5, 4, 5], &registers, 100),
+            Err(VmError::MissingOperand { ip: 2 })
+        );
+        assert_eq!(
+            run_program(&[2, 7], &registers, 100),
+            Err(VmError::ReservedOperand { ip: 0 })
+        );
+        assert_eq!(
+            run_program(&[6, 4], &[64, 0, 0, 0], 100),
+            Err(VmError::ShiftOverflow { ip: 0, shift: 64 })
+        );
+
+        let (steps, result) = trace(&[5, 4, 8, 0], &registers, 100);
+
+        assert_eq!(steps.len(), 1);
+        assert_eq!(result.unwrap_err().to_string(), "invalid opcode 8 at ip 2");
+    }
+
+    #[test]
+    pub fn test_breakpoints() {
+        let breakpoint = Breakpoint::parse("ip==4").unwrap();
+
+        assert_eq!(
+            breakpoint,
+            Breakpoint {
+                register: 3,
+                comparison: Comparison::Equal,
+                value: 4,
+            }
+        );
+        assert!(breakpoint.holds(&[0, 0, 0, 4]));
+        assert!(Breakpoint::parse(" A <= 8 ").unwrap().holds(&[8, 0, 0, 0]));
+        assert!(!Breakpoint::parse("B > 8").unwrap().holds(&[0, 8, 0, 0]));
+        assert_eq!(
+            Breakpoint::parse("D == 1"),
+            Err("unknown register `D`".to_string())
+        );
+        assert_eq!(
+            Breakpoint::parse("A = 1"),
+            Err("expected a comparison like `A == 0`".to_string())
+        );
+
+        let (instructions, registers) = parse_input(TEST_INPUT).unwrap();
+        let context = Context::new(Level::Info, None).with_breakpoints(vec![
+            "A < 100".to_string(),
+            "ip == 4".to_string(),
+            "x".to_string(),
+        ]);
+
+        part1(&instructions, &registers, &context);
+
+        let logs: Vec<String> = context
+            .take_logs()
+            .into_iter()
+            .map(|log| log.message.lines().next().unwrap().to_string())
+            .collect();
+
+        // `A < 100` holds from the third loop on but is only hit once, `ip == 4` is hit every loop
+        assert_eq!(
+            logs[0],
+            "Skipping the breakpoint `x`, expected a comparison like `A == 0`"
+        );
+        assert_eq!(logs.iter().filter(|l| l.contains("A < 100")).count(), 1);
+        assert_eq!(logs.iter().filter(|l| l.contains("ip == 4")).count(), 10);
+    }
+
+    #[test]
+    pub fn test_part2() {
+        let (instructions, _) = parse_input(P2_TEST_INPUT).unwrap();
+
+        assert_eq!(part2(&instructions, &Context::silent()), 117440);
+    }
+
+    #[test]
+    pub fn test_quine_search() {
+        // The usual shape, a shift split over two `adv`s and two outputs for a shift of 6 bits, and
+        // a `cdv` after the shift whose outputs reach 10 bits past the 3 bits the next loop gets
+        let programs: [(&[usize], usize); 4] = [
+            (
+                &[2, 4, 1, 1, 7, 5, 1, 5, 4, 0, 5, 5, 0, 3, 3, 0],
+                164279024971453,
+            ),
+            (
+                &[2, 4, 1, 4, 7, 5, 1, 5, 4, 0, 5, 5, 0, 1, 0, 2, 3, 0],
+                3060937062916280,
+            ),
+            (
+                &[2, 4, 1, 1, 5, 5, 0, 3, 2, 4, 1, 1, 5, 5, 0, 3, 3, 0],
+                2891991184982059,
+            ),
+            (
+                &[2, 4, 0, 3, 1, 3, 7, 5, 1, 2, 4, 0, 5, 5, 3, 0],
+                50741930689609,
+            ),
+        ];
+
+        for (program, quine) in programs {
+            let analysis = analyse(program).unwrap();
+
+            assert_eq!(search_quine(program, &analysis, 0, 0, 0), Some(quine));
+            assert_eq!(
+                run_program(program, &[quine, 0, 0, 0], 1000).unwrap(),
+                program
+            );
+        }
+
+        assert_eq!(
+            analyse(&[2, 4, 1, 1, 7, 5, 1, 5, 4, 0, 5, 5, 0, 3, 3, 0])
+                .unwrap()
+                .to_string(),
+            "Every loop shifts A by 3 bits and outputs 1 value, \
+             the outputs depend on the low 10 bits of A"
+        );
+        assert_eq!(
+            analyse(&[2, 4, 1, 1, 5, 5, 0, 3, 2, 4, 1, 1, 5, 5, 0, 3, 3, 0]),
+            Ok(Analysis {
+                shift: 6,
+                outputs: 2,
+                window: 6,
+            })
+        );
+        assert_eq!(
+            analyse(&[2, 4, 0, 3, 1, 3, 7, 5, 1, 2, 4, 0, 5, 5, 3, 0]),
+            Ok(Analysis {
+                shift: 3,
+                outputs: 1,
+                window: 13,
+            })
+        );
+
+        // Every loop of the example shifts A by 1 bit but outputs 3 of them
+        let (instructions, _) = parse_input(TEST_INPUT).unwrap();
+        let analysis = analyse(&instructions).unwrap();
+
+        assert_eq!(analysis.window, 4);
+        assert_eq!(search_quine(&instructions, &analysis, 0, 0, 0), None);
+    }
+
+    #[test]
+    pub fn test_analyse_rejects_other_programs() {
+        let error = |program: &[usize]| analyse(program).unwrap_err();
+
+        assert_eq!(
+            error(&[5, 4, 3, 0, 0, 3]),
+            "the program is not a single loop that ends with `jnz 0`"
+        );
+        assert_eq!(
+            error(&[1, 1, 5, 5, 0, 3, 3, 0]),
+            "B is read at ip 0 before the loop writes it, so it carries over between loops"
+        );
+        assert_eq!(
+            error(&[2, 4, 0, 5, 5, 4, 3, 0]),
+            "A is shifted by B at ip 2, the shift has to be the same every loop"
+        );
+        assert_eq!(
+            error(&[5, 4, 3, 0]),
+            "A is never shifted, so the program only halts when A is 0"
+        );
+        assert_eq!(
+            error(&[2, 4, 0, 3, 3, 0]),
+            "the program does not output anything"
+        );
+        assert_eq!(
+            error(&[5, 4, 5, 4, 5, 4, 0, 3, 3, 0]),
+            "the program outputs 3 values every loop, that never adds up to its 10 values"
+        );
+
+        assert_eq!(
+            error(&[2, 4, 7, 4, 5, 6, 0, 3, 3, 0]),
+            "C is A shifted by A at ip 2, so the outputs can depend on every bit of A"
+        );
+    }
+
+    #[test]
+    pub fn test_assemble() {
+        let source = "
+            ; Outputs the digits of A in base 8, lowest first
+            A = 2024
+
+            start:
+                adv 3       # A = A >> 3
+                out A
+                jnz start
+        ";
+
+        let input = assemble(source).unwrap();
+
+        assert_eq!(
+            input,
+            "Register A: 2024\nRegister B: 0\nRegister C: 0\n\nProgram: 0,3,5,4,3,0\n"
+        );
+        assert_eq!(
+            parse_input(input.trim_end()).unwrap(),
+            parse_input(P2_TEST_INPUT).unwrap()
+        );
+        assert_eq!(
+            assemble("loop: bxc 5\nbst combo(4)=A\n4: cdv B\nbdv 3 ; end\njnz loop").unwrap(),
+            "Register A: 0\nRegister B: 0\nRegister C: 0\n\nProgram: 4,5,2,4,7,5,6,3,3,0\n"
+        );
+    }
+
+    #[test]
+    pub fn test_assemble_round_trip() {
+        for input in [TEST_INPUT, P2_TEST_INPUT] {
+            let source = disassemble_input(input).unwrap();
+
+            assert_eq!(
+                parse_input(assemble(&source).unwrap().trim_end()).unwrap(),
+                parse_input(input).unwrap()
+            );
+        }
+
+        let source = disassemble_input(
+            "Register A: 7\nRegister B: 1\nRegister C: 2\n\nProgram: 4,3,1,7,7,6,3,4",
+        )
+        .unwrap();
+
+        assert!(source.starts_with("A = 7\nB = 1\nC = 2\n\n  0: bxc 3 "));
+        assert_eq!(
+            assemble(&source).unwrap(),
+            "Register A: 7\nRegister B: 1\nRegister C: 2\n\nProgram: 4,3,1,7,7,6,3,4\n"
+        );
+    }
+
+    #[test]
+    pub fn test_assemble_errors() {
+        let error = |source| assemble(source).unwrap_err().to_string();
+
+        assert_eq!(
+            error("adv 3\nmul A"),
+            "line 2, column 1: unknown instruction `mul`"
+        );
+        assert_eq!(error("  out"), "line 1, column 3: `out` needs an operand");
+        assert_eq!(
+            error("bxl 8"),
+            "line 1, column 5: expected a literal operand from 0 to 7, found `8`"
+        );
+        assert_eq!(
+            error("out D"),
+            "line 1, column 5: expected a combo operand, 0 to 3, A, B or C, found `D`"
+        );
+        assert_eq!(
+            error("adv 3 4"),
+            "line 1, column 7: unexpected `4` after the operand"
+        );
+        assert_eq!(error("jnz end"), "line 1, column 5: undefined label `end`");
+        assert_eq!(
+            error("a: a: out A"),
+            "line 1, column 4: the label `a` is defined twice"
+        );
+        assert_eq!(
+            error("out A\n0: out B"),
+            "line 2, column 1: this instruction is at address 2, not 0"
+        );
+        assert_eq!(
+            error("A = x"),
+            "line 1, column 5: expected a number, found `x`"
+        );
+        assert_eq!(
+            error("out 1\nout 2\nout 3\nout 3\nend: jnz end"),
+            "line 5, column 10: the label `end` is at address 8, `jnz` can only jump up to 7"
+        );
+    }
+}
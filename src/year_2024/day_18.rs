@@ -0,0 +1,139 @@
+use crate::context::Context;
+use crate::solution::{Answer, Solution};
+use crate::utils::grid::Grid;
+use crate::utils::movement::{Direction, Point};
+use crate::utils::parse::{self, ParseError};
+use pathfinding::prelude::dijkstra;
+use std::collections::HashSet;
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Parsed = Vec<Point>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, bytes: &Self::Parsed, context: &Context) -> Answer {
+        part1(bytes, 71, 71, 1024, context).into()
+    }
+
+    fn part2(&self, bytes: &Self::Parsed, _: &Context) -> Answer {
+        part2(bytes, 71, 71)
+            .expect("No byte blocks the path to the exit")
+            .into()
+    }
+}
+
+fn part1(bytes: &[Point], rows: usize, cols: usize, steps: usize, context: &Context) -> usize {
+    let memory = corrupt(&bytes[0..steps], cols, rows);
+
+    let (path, _) = do_the_dijkstra(&memory, Point::default()).unwrap();
+
+    context.frame("shortest path", || draw_grid(&memory, &path));
+
+    path.len() - 1
+}
+
+fn part2(bytes: &[Point], rows: usize, cols: usize) -> Option<String> {
+    let start = Point::default();
+    let mut possible_path_tiles: HashSet<Point> = HashSet::new();
+
+    for steps in 1..bytes.len() {
+        let chunk = &bytes[0..steps];
+        let last = chunk.last();
+
+        // If the byte didn't fall on a known path, it won't cause a blockage (yet)
+        if last.is_some()
+            && !possible_path_tiles.is_empty()
+            && !possible_path_tiles.contains(last.unwrap())
+        {
+            continue;
+        }
+
+        if let Some((path, _)) = do_the_dijkstra(&corrupt(chunk, cols, rows), start) {
+            possible_path_tiles.extend(path);
+        } else {
+            let Point { x, y } = last.unwrap();
+
+            return Some(format!("{},{}", x, y));
+        }
+    }
+
+    None
+}
+
+/// The memory space with the fallen bytes marked as corrupted.
+fn corrupt(bytes: &[Point], cols: usize, rows: usize) -> Grid<bool> {
+    let mut memory = Grid::new(cols, rows, false);
+
+    for byte in bytes {
+        memory[*byte] = true;
+    }
+
+    memory
+}
+
+fn do_the_dijkstra(memory: &Grid<bool>, start: Point) -> Option<(Vec<Point>, usize)> {
+    let exit = Point::new(memory.width() as isize - 1, memory.height() as isize - 1);
+
+    dijkstra(
+        &start,
+        |p| -> Vec<(Point, usize)> {
+            memory
+                .neighbours(*p, &Direction::ORTHOGONAL)
+                .filter(|(_, corrupted)| !**corrupted)
+                .map(|(p, _)| (p, 1))
+                .collect()
+        },
+        |p| *p == exit,
+    )
+}
+
+fn draw_grid(memory: &Grid<bool>, path: &[Point]) -> String {
+    let mut map = memory.map(|corrupted| if *corrupted { '#' } else { '.' });
+
+    for p in path {
+        map[*p] = 'O';
+    }
+
+    map.to_string()
+}
+
+fn parse_input(input: &str) -> Result<Vec<Point>, ParseError> {
+    parse::lines(input, |line| {
+        let [x, y] = parse::integers_exact(input, line)?;
+
+        Ok(Point::new(x, y))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = include_str!("../../inputs/2024/day_18/test");
+
+    #[test]
+    pub fn test_part1() {
+        assert_eq!(
+            part1(
+                &parse_input(TEST_INPUT).unwrap(),
+                7,
+                7,
+                12,
+                &Context::silent()
+            ),
+            22
+        );
+    }
+
+    #[test]
+    pub fn test_part2() {
+        assert_eq!(
+            part2(&parse_input(TEST_INPUT).unwrap(), 7, 7).unwrap(),
+            "6,1"
+        );
+    }
+}
@@ -1,18 +1,32 @@
+use crate::context::Context;
+use crate::solution::{Answer, Solution};
+use crate::utils::parse::{self, ParseError};
 use std::collections::{HashMap, HashSet};
 
-pub fn execute(input: &str) {
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
-}
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Parsed = (HashSet<String>, Vec<String>);
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
 
-fn part1(input: &str) -> usize {
-    let (towels, combinations) = parse_input(input);
+    fn part1(&self, (towels, combinations): &Self::Parsed, _: &Context) -> Answer {
+        part1(towels, combinations).into()
+    }
 
+    fn part2(&self, (towels, combinations): &Self::Parsed, _: &Context) -> Answer {
+        part2(towels, combinations).into()
+    }
+}
+
+fn part1(towels: &HashSet<String>, combinations: &[String]) -> usize {
     let mut valid_count: usize = 0;
     let mut cache: HashMap<String, bool> = HashMap::new();
 
     for combination in combinations {
-        if is_valid_combination(&combination, &towels, &mut cache) {
+        if is_valid_combination(combination, towels, &mut cache) {
             valid_count += 1;
         }
     }
@@ -20,42 +34,30 @@ fn part1(input: &str) -> usize {
     valid_count
 }
 
-fn part2(input: &str) -> usize {
-    let (towels, combinations) = parse_input(input);
+fn part2(towels: &HashSet<String>, combinations: &[String]) -> usize {
     let mut arrangements: usize = 0;
     let mut cache: HashMap<String, usize> = HashMap::new();
 
     for combination in combinations {
         arrangements =
-            find_all_arrangements(&combination, &towels, &mut vec![], arrangements, &mut cache);
+            find_all_arrangements(combination, towels, &mut vec![], arrangements, &mut cache);
     }
 
     arrangements
 }
 
-fn parse_input(input: &str) -> (HashSet<&str>, Vec<String>) {
-    let mut towels: HashSet<&str> = HashSet::new();
-    let mut combinations: Vec<String> = vec![];
-    let mut parse_mode: u8 = 0;
-
-    input.lines().for_each(|line| {
-        if line.is_empty() {
-            parse_mode = 1;
-        } else if parse_mode == 0 {
-            line.split(", ").for_each(|s| {
-                towels.insert(s);
-            });
-        } else {
-            combinations.push(line.to_string());
-        }
-    });
+fn parse_input(input: &str) -> Result<(HashSet<String>, Vec<String>), ParseError> {
+    let [towels, combinations] = parse::sections_exact(input)?;
 
-    (towels, combinations)
+    Ok((
+        towels.split(',').map(|s| s.trim().to_string()).collect(),
+        combinations.lines().map(|l| l.to_string()).collect(),
+    ))
 }
 
 fn find_all_arrangements(
     combination: &str,
-    towel_set: &HashSet<&str>,
+    towel_set: &HashSet<String>,
     current: &mut Vec<String>,
     mut counter: usize,
     cache: &mut HashMap<String, usize>,
@@ -92,7 +94,7 @@ fn find_all_arrangements(
 
 fn is_valid_combination(
     combination: &str,
-    towel_set: &HashSet<&str>,
+    towel_set: &HashSet<String>,
     cache: &mut HashMap<String, bool>,
 ) -> bool {
     if let Some(&result) = cache.get(combination) {
@@ -126,15 +128,19 @@ fn is_valid_combination(
 mod tests {
     use super::*;
 
-    const TEST_INPUT: &str = include_str!("../inputs/day_19/test");
+    const TEST_INPUT: &str = include_str!("../../inputs/2024/day_19/test");
 
     #[test]
     pub fn test_part1() {
-        assert_eq!(part1(TEST_INPUT), 6);
+        let (towels, combinations) = parse_input(TEST_INPUT).unwrap();
+
+        assert_eq!(part1(&towels, &combinations), 6);
     }
 
     #[test]
     pub fn test_part2() {
-        assert_eq!(part2(TEST_INPUT), 16);
+        let (towels, combinations) = parse_input(TEST_INPUT).unwrap();
+
+        assert_eq!(part2(&towels, &combinations), 16);
     }
 }
@@ -0,0 +1,88 @@
+use crate::context::Context;
+use crate::solution::{Answer, Solution};
+use crate::utils::parse::{self, ParseError};
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Parsed = Vec<Vec<i32>>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, reports: &Self::Parsed, _: &Context) -> Answer {
+        part1(reports).into()
+    }
+
+    fn part2(&self, reports: &Self::Parsed, _: &Context) -> Answer {
+        part2(reports).into()
+    }
+}
+
+fn part1(reports: &[Vec<i32>]) -> usize {
+    reports.iter().filter(|v| is_safe(v)).count()
+}
+
+fn part2(reports: &[Vec<i32>]) -> usize {
+    reports.iter().filter(|v| try_is_safe(v)).count()
+}
+
+fn parse_input(input: &str) -> Result<Vec<Vec<i32>>, ParseError> {
+    parse::lines(input, |line| parse::integers(input, line))
+}
+
+fn is_safe(items: &Vec<i32>) -> bool {
+    if !(1..=3).contains(&(items[1] - items[0]).abs()) {
+        return false;
+    }
+
+    let increasing: bool = items[1] > items[0];
+
+    for i in 2..items.len() {
+        let is_increasing = items[i] > items[i - 1];
+        if is_increasing != increasing {
+            return false;
+        }
+
+        if !(1..=3).contains(&(items[i] - items[i - 1]).abs()) {
+            return false;
+        }
+    }
+
+    true
+}
+
+fn try_is_safe(items: &Vec<i32>) -> bool {
+    if is_safe(items) {
+        return true;
+    }
+
+    for i in 0..items.len() {
+        let mut copied = items.clone();
+        copied.remove(i);
+
+        if is_safe(&copied) {
+            return true;
+        }
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = include_str!("../../inputs/2024/day_2/test");
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&parse_input(TEST_INPUT).unwrap()), 2);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&parse_input(TEST_INPUT).unwrap()), 4);
+    }
+}
@@ -0,0 +1,171 @@
+use pathfinding::prelude::dijkstra;
+use std::collections::{HashMap, HashSet};
+
+use crate::context::Context;
+use crate::solution::{Answer, Solution};
+use crate::timing;
+use crate::utils::grid::Grid;
+use crate::utils::movement::{Direction, Point};
+use crate::utils::parse::ParseError;
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Parsed = (Point, Point, Maze);
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, (start, end, maze): &Self::Parsed, context: &Context) -> Answer {
+        run(start, end, maze, 100, 2, context).into()
+    }
+
+    fn part2(&self, (start, end, maze): &Self::Parsed, context: &Context) -> Answer {
+        run(start, end, maze, 100, 20, context).into()
+    }
+}
+
+type Maze = Grid<bool>;
+
+fn run(
+    start: &Point,
+    end: &Point,
+    maze: &Maze,
+    required_time_save: usize,
+    skippable: usize,
+    context: &Context,
+) -> usize {
+    let cheats: HashMap<usize, usize> = cheat_the_maze(maze, start, end, skippable, context);
+
+    count_cheats(&cheats, &required_time_save)
+}
+
+fn count_cheats(cheats: &HashMap<usize, usize>, required_time_save: &usize) -> usize {
+    cheats.into_iter().fold(0, |acc, (score, count)| {
+        if score >= required_time_save {
+            return acc + count;
+        }
+
+        acc
+    })
+}
+
+fn cheat_the_maze(
+    maze: &Maze,
+    start: &Point,
+    end: &Point,
+    skippable: usize,
+    context: &Context,
+) -> HashMap<usize, usize> {
+    let (true_path, _) = timing::phase("shortest path", || {
+        dijkstra(start, |p| successors(p, maze), |p| p == end).unwrap()
+    });
+
+    let mut cheats: HashMap<usize, usize> = HashMap::new();
+    let mut visited: HashSet<Point> = HashSet::new();
+
+    timing::phase("find cheats", || {
+        true_path.iter().enumerate().for_each(|(index, p)| {
+            visited.insert(*p);
+
+            let jumpable_targets = can_jump_to(p, &true_path, &skippable);
+
+            context.frame("cheats", || {
+                draw_maze(maze, start, end, &true_path[..=index], &jumpable_targets)
+            });
+
+            for (target, distance) in jumpable_targets {
+                if !visited.contains(&target) {
+                    let start_index = true_path.iter().position(|p| p == &target).unwrap();
+
+                    let saved = start_index - index - distance;
+
+                    cheats.entry(saved).or_insert(0);
+                    cheats.entry(saved).and_modify(|count| {
+                        *count += 1;
+                    });
+                }
+            }
+        });
+    });
+
+    cheats
+}
+
+fn successors(position: &Point, maze: &Maze) -> Vec<(Point, usize)> {
+    maze.neighbours(*position, &Direction::ORTHOGONAL)
+        .filter(|(_, open)| **open)
+        .map(|(p, _)| (p, 1))
+        .collect()
+}
+
+fn can_jump_to(position: &Point, path: &[Point], skippable: &usize) -> HashSet<(Point, usize)> {
+    let mut jumpable_positions: HashSet<(Point, usize)> = HashSet::new();
+    let current_index = path.iter().position(|p| p == position).unwrap();
+
+    for pos in path[current_index..].iter() {
+        let distance = position.manhattan(*pos);
+        if pos == &path[current_index + distance] {
+            continue;
+        }
+
+        if distance <= *skippable {
+            jumpable_positions.insert((*pos, distance));
+        }
+    }
+
+    jumpable_positions
+}
+
+/// Draws the path walked so far and the positions a cheat from its end can jump to.
+fn draw_maze(
+    maze: &Maze,
+    start: &Point,
+    end: &Point,
+    path: &[Point],
+    jumpable_positions: &HashSet<(Point, usize)>,
+) -> String {
+    let mut map = maze.map(|open| if *open { '.' } else { '█' });
+
+    for position in path {
+        map[*position] = '*';
+    }
+
+    for (position, _) in jumpable_positions {
+        map[*position] = 'J';
+    }
+
+    map[*start] = 'S';
+    map[*end] = 'E';
+    map.to_string()
+}
+
+fn parse_input(input: &str) -> Result<(Point, Point, Maze), ParseError> {
+    let tiles = Grid::parse(input, |c| "#.SE".contains(c).then_some(c))?;
+    let start = tiles.find(&'S').unwrap_or_default();
+    let end = tiles.find(&'E').unwrap_or_default();
+
+    Ok((start, end, tiles.map(|c| *c != '#')))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = include_str!("../../inputs/2024/day_20/test");
+
+    #[test]
+    pub fn test_part1() {
+        let (start, end, maze) = parse_input(TEST_INPUT).unwrap();
+
+        assert_eq!(run(&start, &end, &maze, 10, 2, &Context::silent()), 10);
+    }
+
+    #[test]
+    pub fn test_part2() {
+        let (start, end, maze) = parse_input(TEST_INPUT).unwrap();
+
+        assert_eq!(run(&start, &end, &maze, 50, 20, &Context::silent()), 45);
+    }
+}
@@ -1,17 +1,30 @@
-pub fn execute(input: &str) {
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
-}
+use crate::context::Context;
+use crate::solution::{Answer, Solution};
+use crate::utils::parse::{self, ParseError};
 
-fn part1(input: &str) -> usize {
-    let nums: Vec<usize> = input.lines().map(|l| l.parse().unwrap()).collect();
+pub struct Day22;
 
-    nums.iter().fold(0, |acc, n| acc + process(n))
+impl Solution for Day22 {
+    type Parsed = Vec<usize>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, nums: &Self::Parsed, _: &Context) -> Answer {
+        part1(nums).into()
+    }
+
+    fn part2(&self, nums: &Self::Parsed, _: &Context) -> Answer {
+        part2(nums).into()
+    }
 }
 
-fn part2(input: &str) -> isize {
-    let nums: Vec<usize> = input.lines().map(|l| l.parse().unwrap()).collect();
+fn part1(nums: &[usize]) -> usize {
+    nums.iter().fold(0, |acc, n| acc + process(n))
+}
 
+fn part2(nums: &[usize]) -> isize {
     // when_does_the_sequence_occur(&make_change_map(&123))
 
     nums.iter().fold(0, |acc, n| {
@@ -19,6 +32,10 @@ fn part2(input: &str) -> isize {
     })
 }
 
+fn parse_input(input: &str) -> Result<Vec<usize>, ParseError> {
+    parse::lines(input, |line| parse::number(input, line))
+}
+
 fn process(num: &usize) -> usize {
     let mut secret: usize = *num;
 
@@ -73,16 +90,16 @@ fn make_change_map(num: &usize) -> Vec<(usize, isize)> {
 mod tests {
     use super::*;
 
-    const P1_TEST_INPUT: &str = include_str!("../inputs/day_22/p1_test");
-    const P2_TEST_INPUT: &str = include_str!("../inputs/day_22/p2_test");
+    const P1_TEST_INPUT: &str = include_str!("../../inputs/2024/day_22/p1_test");
+    const P2_TEST_INPUT: &str = include_str!("../../inputs/2024/day_22/p2_test");
 
     #[test]
     pub fn test_part1() {
-        assert_eq!(part1(P1_TEST_INPUT), 37327623);
+        assert_eq!(part1(&parse_input(P1_TEST_INPUT).unwrap()), 37327623);
     }
 
     #[test]
     pub fn test_part2() {
-        assert_eq!(part2(P2_TEST_INPUT), 23);
+        assert_eq!(part2(&parse_input(P2_TEST_INPUT).unwrap()), 23);
     }
 }
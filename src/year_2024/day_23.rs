@@ -36,8 +36,8 @@ fn part1(_input: &str) -> usize {
 mod tests {
     use super::*;
 
-    const TEST_INPUT: &str = include_str!("../inputs/day_23/test");
-    // const P2_TEST_INPUT: &str = include_str!("../inputs/day_22/p2_test");
+    const TEST_INPUT: &str = include_str!("../../inputs/2024/day_23/test");
+    // const P2_TEST_INPUT: &str = include_str!("../../inputs/2024/day_22/p2_test");
 
     #[test]
     pub fn test_part1() {
@@ -0,0 +1,346 @@
+use crate::context::Context;
+use crate::solution::{Answer, Solution};
+use crate::utils::parse::{self, ParseError};
+use itertools::Itertools;
+use std::collections::{HashMap, VecDeque};
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Parsed = (Registers, VecDeque<Action>);
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, (registers, actions): &Self::Parsed, _: &Context) -> Answer {
+        part1(registers, actions).into()
+    }
+
+    fn part2(&self, (_, actions): &Self::Parsed, context: &Context) -> Answer {
+        part2(actions, context).into()
+    }
+}
+
+fn part1(registers: &Registers, actions: &VecDeque<Action>) -> usize {
+    let mut registers = registers.clone();
+    let mut actions = actions.clone();
+
+    while !actions.is_empty() {
+        let (source1, action, source2, target) = actions.pop_front().unwrap();
+
+        if !registers.contains_key(&source1) || !registers.contains_key(&source2) {
+            // Cannot process this action yet, some registers are not set
+            actions.push_back((source1, action, source2, target));
+
+            continue;
+        }
+
+        if action == ActionType::AND {
+            registers.insert(target, registers[&source1] && registers[&source2]);
+        } else if action == ActionType::OR {
+            registers.insert(target, registers[&source1] || registers[&source2]);
+        } else if action == ActionType::XOR {
+            registers.insert(target, registers[&source1] ^ registers[&source2]);
+        }
+    }
+
+    let binary_string: String = registers
+        .iter()
+        .filter(|(key, _value)| key.starts_with('z'))
+        .map(|(key, value)| (key.clone(), *value))
+        .sorted_by(|(a, _), (b, _)| b.cmp(a))
+        .map(|(_key, value)| if value { '1' } else { '0' })
+        .collect();
+
+    usize::from_str_radix(&binary_string, 2).unwrap_or(0)
+}
+
+/// Repairs the circuit into an adder of x and y, one swap of two gate outputs at a time, and
+/// returns the swapped wires.
+fn part2(actions: &VecDeque<Action>, context: &Context) -> String {
+    let mut gates: Vec<Action> = actions.iter().cloned().collect();
+    let mut swapped: Vec<String> = vec![];
+
+    while let Some((a, b)) =
+        next_swap(&gates).unwrap_or_else(|e| panic!("Cannot repair the adder, {}", e))
+    {
+        if swapped.len() >= gates.len() {
+            panic!("Cannot repair the adder, swapping outputs does not converge");
+        }
+
+        context.debug(|| format!("Swapping the outputs {} and {}", a, b));
+
+        for gate in gates.iter_mut() {
+            if gate.3 == a {
+                gate.3 = b.clone();
+            } else if gate.3 == b {
+                gate.3 = a.clone();
+            }
+        }
+
+        swapped.extend([a, b]);
+    }
+
+    swapped.sort();
+    swapped.join(",")
+}
+
+/// Walks the circuit as a ripple-carry adder from the lowest bit up, every bit `i` after the
+/// first being
+///
+/// ```text
+/// s = xi XOR yi, a = xi AND yi, zi = s XOR carry, t = s AND carry, carry = a OR t
+/// ```
+///
+/// and the last carry being the highest z. Returns the first two outputs that have to be swapped
+/// to get closer to that, or None when the circuit already is the adder. Swaps that leave the
+/// circuit adding the same, like the two inputs of an OR, are not found.
+fn next_swap(gates: &[Action]) -> Result<Option<(String, String)>, String> {
+    let find = |action: ActionType, a: &str, b: &str| {
+        gates
+            .iter()
+            .find(|(in1, op, in2, _)| {
+                *op == action && ((in1 == a && in2 == b) || (in1 == b && in2 == a))
+            })
+            .map(|gate| gate.3.clone())
+    };
+    let expect = |action: ActionType, a: &str, b: &str| {
+        find(action.clone(), a, b)
+            .ok_or_else(|| format!("there is no gate for {} {:?} {}", a, action, b))
+    };
+    // The other input of the gate that should combine `a` and `b` but only has one of them
+    let other_input = |action: ActionType, a: &str, b: &str| {
+        gates
+            .iter()
+            .filter(|(_, op, _, _)| *op == action)
+            .find_map(|(in1, _, in2, _)| match (in1.as_str(), in2.as_str()) {
+                (x, other) | (other, x) if x == a => Some((b.to_string(), other.to_string())),
+                (x, other) | (other, x) if x == b => Some((a.to_string(), other.to_string())),
+                _ => None,
+            })
+            .ok_or_else(|| format!("no {:?} gate reads {} or {}", action, a, b))
+    };
+
+    let bits = gates
+        .iter()
+        .flat_map(|(in1, _, in2, _)| [in1, in2])
+        .filter(|wire| wire.starts_with('x'))
+        .unique()
+        .count();
+    let mut carry: Option<String> = None;
+
+    for bit in 0..bits {
+        let (x, y, z) = (
+            format!("x{:02}", bit),
+            format!("y{:02}", bit),
+            format!("z{:02}", bit),
+        );
+        let sum = expect(ActionType::XOR, &x, &y)?;
+        let and = expect(ActionType::AND, &x, &y)?;
+
+        let Some(carry_in) = carry else {
+            if sum != z {
+                return Ok(Some((sum, z)));
+            }
+
+            carry = Some(and);
+            continue;
+        };
+
+        match find(ActionType::XOR, &sum, &carry_in) {
+            Some(output) if output != z => return Ok(Some((output, z))),
+            Some(_) => {}
+            None => return other_input(ActionType::XOR, &sum, &carry_in).map(Some),
+        }
+
+        let carried = expect(ActionType::AND, &sum, &carry_in)?;
+
+        carry = match find(ActionType::OR, &and, &carried) {
+            Some(output) => Some(output),
+            None => return other_input(ActionType::OR, &and, &carried).map(Some),
+        };
+    }
+
+    let last = format!("z{:02}", bits);
+
+    match carry {
+        Some(carry) if carry != last => Ok(Some((carry, last))),
+        _ => Ok(None),
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ActionType {
+    AND,
+    OR,
+    XOR,
+}
+
+type Registers = HashMap<String, bool>;
+type Action = (String, ActionType, String, String);
+
+fn parse_input(input: &str) -> Result<(Registers, VecDeque<Action>), ParseError> {
+    let [wires, gates] = parse::sections_exact(input)?;
+
+    let registers: Registers = parse::lines(wires, |line| {
+        let (register, value) = parse::key_value(input, line)?;
+
+        Ok((register.to_string(), value == "1"))
+    })?
+    .into_iter()
+    .collect();
+
+    let actions = parse::lines(gates, |l| {
+        let parts: Vec<&str> = l.split_whitespace().collect();
+
+        if parts.len() != 5 || parts[3] != "->" {
+            return Err(ParseError::at(input, l, "expected `a OP b -> c`"));
+        }
+
+        let action = match parts[1] {
+            "AND" => ActionType::AND,
+            "OR" => ActionType::OR,
+            "XOR" => ActionType::XOR,
+            _ => {
+                return Err(ParseError::at(
+                    input,
+                    parts[1],
+                    format!("unknown action `{}`", parts[1]),
+                ))
+            }
+        };
+
+        Ok((
+            parts[0].to_string(),
+            action,
+            parts[2].to_string(),
+            parts[4].to_string(),
+        ))
+    })?;
+
+    Ok((registers, actions.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = include_str!("../../inputs/2024/day_24/test");
+
+    #[test]
+    pub fn test_part1() {
+        let (registers, actions) = parse_input(TEST_INPUT).unwrap();
+
+        assert_eq!(part1(&registers, &actions), 2024);
+    }
+
+    /// A ripple-carry adder of two numbers of `bits` bits, with the outputs of the gate pairs in
+    /// `swaps` swapped.
+    fn adder(bits: usize, swaps: &[(&str, &str)]) -> VecDeque<Action> {
+        let mut gates: Vec<Action> = vec![];
+        let mut gate = |a: String, action: ActionType, b: String, output: String| {
+            let output = swaps
+                .iter()
+                .find_map(|&(first, second)| match output.as_str() {
+                    o if o == first => Some(second.to_string()),
+                    o if o == second => Some(first.to_string()),
+                    _ => None,
+                })
+                .unwrap_or(output);
+
+            gates.push((a, action, b, output));
+        };
+
+        for bit in 0..bits {
+            let (x, y, z) = (
+                format!("x{:02}", bit),
+                format!("y{:02}", bit),
+                format!("z{:02}", bit),
+            );
+            let carry_in = format!("c{:02}", bit.saturating_sub(1));
+            let carry_out = match bit + 1 {
+                last if last == bits => format!("z{:02}", last),
+                _ => format!("c{:02}", bit),
+            };
+
+            if bit == 0 {
+                gate(x.clone(), ActionType::XOR, y.clone(), z);
+                gate(y, ActionType::AND, x, carry_out);
+                continue;
+            }
+
+            let (sum, and, carried) = (
+                format!("s{:02}", bit),
+                format!("a{:02}", bit),
+                format!("t{:02}", bit),
+            );
+
+            gate(x.clone(), ActionType::XOR, y.clone(), sum.clone());
+            gate(y, ActionType::AND, x, and.clone());
+            gate(carry_in.clone(), ActionType::XOR, sum.clone(), z);
+            gate(sum, ActionType::AND, carry_in, carried.clone());
+            gate(carried, ActionType::OR, and, carry_out);
+        }
+
+        // The gates are listed from the highest bit down, so evaluating them has to wait for inputs
+        gates.into_iter().rev().collect()
+    }
+
+    fn registers(bits: usize, x: usize, y: usize) -> Registers {
+        (0..bits)
+            .flat_map(|bit| {
+                [
+                    (format!("x{:02}", bit), x >> bit & 1 == 1),
+                    (format!("y{:02}", bit), y >> bit & 1 == 1),
+                ]
+            })
+            .collect()
+    }
+
+    #[test]
+    pub fn test_adder() {
+        let gates = adder(6, &[]);
+
+        for (x, y) in [(0, 0), (1, 1), (21, 42), (63, 63), (37, 58)] {
+            assert_eq!(part1(&registers(6, x, y), &gates), x + y);
+        }
+
+        assert_eq!(part2(&gates, &Context::silent()), "");
+    }
+
+    #[test]
+    pub fn test_part2() {
+        let cases: [&[(&str, &str)]; 7] = [
+            // The sum and carry of the inputs of a bit
+            &[("s03", "a03")],
+            // A sum bit with the carry out of its bit, and with the sum of another bit
+            &[("z04", "c04"), ("z02", "s05")],
+            // The carry of the sum with the carry in, and the two lowest and highest outputs
+            &[("z06", "t06")],
+            &[("z00", "z01")],
+            &[("z07", "z08")],
+            // The carry of the first bit is its AND gate, not an OR gate
+            &[("c00", "a01")],
+            // Four pairs, like the puzzle has
+            &[
+                ("z05", "t05"),
+                ("s02", "a02"),
+                ("z01", "c01"),
+                ("a06", "z07"),
+            ],
+        ];
+
+        for swaps in cases {
+            let mut expected: Vec<&str> = swaps.iter().flat_map(|&(a, b)| [a, b]).collect();
+            expected.sort();
+
+            assert_eq!(
+                part2(&adder(8, swaps), &Context::silent()),
+                expected.join(","),
+                "swapping {:?}",
+                swaps
+            );
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use crate::context::Context;
+use crate::solution::{Answer, Part, Solution};
+use crate::utils::parse::{self, ParseError};
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Parsed = (Vec<Pins>, Vec<Pins>);
+
+    const PARTS: &'static [Part] = &[Part::One];
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, (keys, locks): &Self::Parsed, _: &Context) -> Answer {
+        part1(keys, locks).into()
+    }
+}
+
+fn part1(keys: &[[usize; 5]], locks: &[[usize; 5]]) -> usize {
+    let mut keys_that_fit: usize = 0;
+
+    locks.iter().for_each(|lock| {
+        keys.iter().for_each(|key| {
+            for i in 0..5 {
+                if lock[i] + key[i] > 5 {
+                    return;
+                }
+            }
+
+            keys_that_fit += 1;
+        })
+    });
+
+    keys_that_fit
+}
+
+// fn part2(input: &str) -> isize {
+//     let nums: Vec<usize> = input.lines().map(|l| l.parse().unwrap()).collect();
+//
+//     // when_does_the_sequence_occur(&make_change_map(&123))
+//
+//     nums.iter().fold(0, |acc, n| {
+//         acc + when_does_the_sequence_occur(&make_change_map(n))
+//     })
+// }
+
+type Pins = [usize; 5];
+
+fn parse_input(input: &str) -> Result<(Vec<Pins>, Vec<Pins>), ParseError> {
+    let mut keys: Vec<Pins> = Vec::new();
+    let mut locks: Vec<Pins> = Vec::new();
+
+    for schematic in parse::sections(input) {
+        let rows: Vec<&str> = schematic.lines().collect();
+
+        if rows.len() != 7 || rows.iter().any(|row| row.chars().count() != 5) {
+            return Err(ParseError::at(
+                input,
+                schematic,
+                "expected a schematic of 7 rows of 5 pins",
+            ));
+        }
+
+        let mut pins: Pins = [0; 5];
+
+        for row in &rows[1..6] {
+            for (i, c) in row.chars().enumerate() {
+                if c == '#' {
+                    pins[i] += 1;
+                }
+            }
+        }
+
+        match rows[0] {
+            "....." => keys.push(pins),
+            "#####" => locks.push(pins),
+            _ => return Err(ParseError::at(input, rows[0], "expected a key or a lock")),
+        }
+    }
+
+    Ok((keys, locks))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = include_str!("../../inputs/2024/day_25/test");
+
+    #[test]
+    pub fn test_part1() {
+        let (keys, locks) = parse_input(TEST_INPUT).unwrap();
+
+        assert_eq!(part1(&keys, &locks), 3);
+    }
+
+    // #[test]
+    // pub fn test_part2() {
+    //     assert_eq!(part2(P2_TEST_INPUT), 23);
+    // }
+}
@@ -1,9 +1,26 @@
 use lazy_static::lazy_static;
 use regex::Regex;
 
-pub fn execute(input: &str) {
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+use crate::context::Context;
+use crate::solution::{Answer, Solution};
+use crate::utils::parse::ParseError;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Parsed = String;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        Ok(input.to_string())
+    }
+
+    fn part1(&self, memory: &Self::Parsed, _: &Context) -> Answer {
+        part1(memory).into()
+    }
+
+    fn part2(&self, memory: &Self::Parsed, _: &Context) -> Answer {
+        part2(memory).into()
+    }
 }
 
 lazy_static! {
@@ -38,8 +55,8 @@ fn part2(input: &str) -> i32 {
 mod tests {
     use super::*;
 
-    const TEST_INPUT: &str = include_str!("../inputs/day_3/test");
-    const TEST_INPUT2: &str = include_str!("../inputs/day_3/test2");
+    const TEST_INPUT: &str = include_str!("../../inputs/2024/day_3/test");
+    const TEST_INPUT2: &str = include_str!("../../inputs/2024/day_3/test2");
 
     #[test]
     pub fn test_part1() {
@@ -0,0 +1,78 @@
+use crate::context::Context;
+use crate::solution::{Answer, Solution};
+use crate::utils::grid::Grid;
+use crate::utils::movement::{Direction, Point};
+use crate::utils::parse::ParseError;
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Parsed = Grid<char>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        Grid::parse(input, Some)
+    }
+
+    fn part1(&self, grid: &Self::Parsed, _: &Context) -> Answer {
+        part1(grid).into()
+    }
+
+    fn part2(&self, grid: &Self::Parsed, _: &Context) -> Answer {
+        part2(grid).into()
+    }
+}
+
+fn part1(grid: &Grid<char>) -> usize {
+    grid.find_all(&'X')
+        .map(|start| {
+            Direction::ALL
+                .iter()
+                .filter(|&&direction| is_xmas(grid, start, direction))
+                .count()
+        })
+        .sum()
+}
+
+fn part2(grid: &Grid<char>) -> usize {
+    grid.find_all(&'A')
+        .filter(|&center| is_x_mas(grid, center))
+        .count()
+}
+
+fn is_xmas(grid: &Grid<char>, start: Point, direction: Direction) -> bool {
+    "XMAS"
+        .chars()
+        .enumerate()
+        .all(|(i, c)| grid.get(start + direction.offset() * i as isize) == Some(&c))
+}
+
+/// Both diagonals through the `A` read `MAS`, in either direction.
+fn is_x_mas(grid: &Grid<char>, center: Point) -> bool {
+    [Direction::UpLeft, Direction::UpRight]
+        .iter()
+        .all(|&corner| {
+            let ends = (
+                grid.get(center.step(corner)),
+                grid.get(center.step(corner.opposite())),
+            );
+
+            matches!(ends, (Some('M'), Some('S')) | (Some('S'), Some('M')))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = include_str!("../../inputs/2024/day_4/test");
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(part1(&Grid::parse(TEST_INPUT, Some).unwrap()), 18);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&Grid::parse(TEST_INPUT, Some).unwrap()), 9);
+    }
+}
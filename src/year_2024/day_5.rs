@@ -0,0 +1,125 @@
+use crate::context::Context;
+use crate::solution::{Answer, Solution};
+use crate::utils::parse::{self, ParseError};
+use std::collections::HashMap;
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Parsed = (Pages, Vec<Vec<i32>>);
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, (pages, orders): &Self::Parsed, _: &Context) -> Answer {
+        part1(pages, orders).into()
+    }
+
+    fn part2(&self, (pages, orders): &Self::Parsed, _: &Context) -> Answer {
+        part2(pages, orders).into()
+    }
+}
+
+fn part1(pages: &HashMap<i32, Vec<i32>>, orders: &[Vec<i32>]) -> i32 {
+    orders.iter().fold(0, |acc, order| {
+        acc + if is_correct_order(&order, pages).is_ok() {
+            order[order.len() / 2]
+        } else {
+            0
+        }
+    })
+}
+
+fn part2(pages: &HashMap<i32, Vec<i32>>, orders: &[Vec<i32>]) -> usize {
+    orders.iter().fold(0, |acc, order| {
+        acc + if is_correct_order(&order, pages).is_err() {
+            let new_order = fix_order(&order, pages);
+
+            new_order[new_order.len() / 2] as usize
+        } else {
+            0
+        }
+    })
+}
+
+type Pages = HashMap<i32, Vec<i32>>;
+
+fn parse_input(input: &str) -> Result<(Pages, Vec<Vec<i32>>), ParseError> {
+    let mut pages: Pages = HashMap::new();
+    let [rules, updates] = parse::sections_exact(input)?;
+
+    for line in rules.lines() {
+        let (before, after) = parse::split_once(input, line, "|")?;
+
+        pages
+            .entry(parse::number(input, before)?)
+            .or_default()
+            .push(parse::number(input, after)?);
+    }
+
+    let orders = parse::lines(updates, |line| parse::list(input, line, ","))?;
+
+    Ok((pages, orders))
+}
+
+fn is_correct_order(order: &Vec<i32>, pages: &HashMap<i32, Vec<i32>>) -> Result<bool, usize> {
+    let mut pages_printed: Vec<i32> = vec![];
+
+    for i in 0..order.len() {
+        let page = &order[i];
+        let page_deps: Option<&Vec<i32>> = pages.get(page);
+
+        if page_deps.is_none() {
+            pages_printed.push(*page);
+
+            continue;
+        }
+
+        for dep in page_deps.unwrap() {
+            if pages_printed.contains(dep) {
+                return Err(i);
+            }
+        }
+
+        pages_printed.push(*page);
+    }
+
+    Ok(true)
+}
+
+fn fix_order(order: &Vec<i32>, pages: &HashMap<i32, Vec<i32>>) -> Vec<i32> {
+    let mut new_order: Vec<i32> = order.clone();
+
+    loop {
+        let result = is_correct_order(&new_order, pages);
+        if result.is_ok() {
+            break;
+        }
+
+        new_order.swap(result.unwrap_err(), result.unwrap_err() - 1);
+    }
+
+    new_order
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = include_str!("../../inputs/2024/day_5/test");
+
+    #[test]
+    pub fn test_part1() {
+        let (pages, orders) = parse_input(TEST_INPUT).unwrap();
+
+        assert_eq!(part1(&pages, &orders), 143);
+    }
+
+    #[test]
+    pub fn test_part2() {
+        let (pages, orders) = parse_input(TEST_INPUT).unwrap();
+
+        assert_eq!(part2(&pages, &orders), 123);
+    }
+}
@@ -0,0 +1,130 @@
+use crate::context::Context;
+use crate::solution::{Answer, Solution};
+use crate::utils::grid::Grid;
+use crate::utils::movement::{Direction, Point};
+use crate::utils::parse::ParseError;
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::thread::JoinHandle;
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Parsed = Grid<char>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        Grid::parse(input, Some)
+    }
+
+    fn part1(&self, grid: &Self::Parsed, _: &Context) -> Answer {
+        part1(grid).into()
+    }
+
+    fn part2(&self, grid: &Self::Parsed, _: &Context) -> Answer {
+        part2(grid).into()
+    }
+}
+
+fn part1(grid: &Grid<char>) -> usize {
+    get_unique_guard_locations(grid).len()
+}
+
+fn part2(grid: &Grid<char>) -> i32 {
+    let start_position: Point = grid.find(&'^').expect("No start position found");
+    let walls: HashSet<Point> = grid.find_all(&'#').collect();
+    let path: HashSet<Point> = get_unique_guard_locations(grid);
+
+    let count = Arc::new(Mutex::new(0));
+
+    let handles: Vec<JoinHandle<()>> = path
+        .into_iter()
+        .map(|location| {
+            let mut new_walls: HashSet<Point> = walls.clone();
+            new_walls.insert(location);
+
+            let thread_grid = grid.clone();
+            let thread_count = Arc::clone(&count);
+
+            thread::spawn(move || {
+                if is_infinite_loop(start_position, &new_walls, &thread_grid) {
+                    let mut num = thread_count.lock().unwrap();
+                    *num += 1;
+                }
+            })
+        })
+        .collect();
+
+    // Wait for threads to complete
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    let x = *count.lock().unwrap();
+    x
+}
+
+fn get_unique_guard_locations(grid: &Grid<char>) -> HashSet<Point> {
+    let mut direction: Direction = Direction::Up;
+    let mut position: Point = grid.find(&'^').expect("No start position found");
+    let walls: HashSet<Point> = grid.find_all(&'#').collect();
+    let mut path: HashSet<Point> = HashSet::new();
+
+    path.insert(position);
+
+    while let Some(next_position) = position.checked_step(direction, grid.width(), grid.height()) {
+        if !walls.contains(&next_position) {
+            position = next_position;
+
+            path.insert(position);
+        } else {
+            direction = direction.turn_right()
+        }
+    }
+
+    path
+}
+
+fn is_infinite_loop(start_position: Point, walls: &HashSet<Point>, grid: &Grid<char>) -> bool {
+    let mut position = start_position;
+    let mut direction: Direction = Direction::Up;
+    let mut path: HashSet<(Point, Direction)> = HashSet::new();
+
+    path.insert((position, direction));
+
+    loop {
+        let Some(next_position) = position.checked_step(direction, grid.width(), grid.height())
+        else {
+            return false;
+        };
+
+        if !walls.contains(&next_position) {
+            position = next_position;
+
+            if path.contains(&(position, direction)) {
+                return true;
+            }
+
+            path.insert((position, direction));
+        } else {
+            direction = direction.turn_right()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = include_str!("../../inputs/2024/day_6/test");
+
+    #[test]
+    pub fn test_part1() {
+        assert_eq!(part1(&Grid::parse(TEST_INPUT, Some).unwrap()), 41);
+    }
+
+    #[test]
+    pub fn test_part2() {
+        assert_eq!(part2(&Grid::parse(TEST_INPUT, Some).unwrap()), 6);
+    }
+}
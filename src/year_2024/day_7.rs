@@ -1,13 +1,26 @@
-use std::str::FromStr;
+use crate::context::Context;
+use crate::solution::{Answer, Solution};
+use crate::utils::parse::{self, ParseError};
 
-pub fn execute(input: &str) {
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
-}
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Parsed = Vec<(i64, Vec<i64>)>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, items: &Self::Parsed, _: &Context) -> Answer {
+        part1(items).into()
+    }
 
-fn part1(input: &str) -> i64 {
-    let items = parse_input(input);
+    fn part2(&self, items: &Self::Parsed, _: &Context) -> Answer {
+        part2(items).into()
+    }
+}
 
+fn part1(items: &[(i64, Vec<i64>)]) -> i64 {
     items
         .iter()
         .filter(|(k, v)| check_combination(*k, v[0], &v[1..], false))
@@ -15,9 +28,7 @@ fn part1(input: &str) -> i64 {
         .sum()
 }
 
-fn part2(input: &str) -> i64 {
-    let items = parse_input(input);
-
+fn part2(items: &[(i64, Vec<i64>)]) -> i64 {
     items
         .iter()
         .filter(|(k, v)| check_combination(*k, v[0], &v[1..], true))
@@ -26,20 +37,15 @@ fn part2(input: &str) -> i64 {
 }
 
 // Build a list of numbers like (190, [10, 19])
-fn parse_input(input: &str) -> Vec<(i64, Vec<i64>)> {
-    input
-        .lines()
-        .into_iter()
-        .map(|line| {
-            let (left, right) = line.split_once(": ").unwrap();
-            let parts = right
-                .split_whitespace()
-                .map(|i| i64::from_str(i).unwrap())
-                .collect();
-
-            (i64::from_str(left).unwrap(), parts)
-        })
-        .collect()
+fn parse_input(input: &str) -> Result<Vec<(i64, Vec<i64>)>, ParseError> {
+    parse::lines(input, |line| {
+        let (target, numbers) = parse::key_value(input, line)?;
+
+        Ok((
+            parse::number(input, target)?,
+            parse::integers(input, numbers)?,
+        ))
+    })
 }
 
 fn check_combination(target: i64, current: i64, numbers: &[i64], with_concat: bool) -> bool {
@@ -68,15 +74,15 @@ fn check_combination(target: i64, current: i64, numbers: &[i64], with_concat: bo
 mod tests {
     use super::*;
 
-    const TEST_INPUT: &str = include_str!("../inputs/day_7/test");
+    const TEST_INPUT: &str = include_str!("../../inputs/2024/day_7/test");
 
     #[test]
     pub fn test_part1() {
-        assert_eq!(part1(TEST_INPUT), 3749);
+        assert_eq!(part1(&parse_input(TEST_INPUT).unwrap()), 3749);
     }
 
     #[test]
     pub fn test_part2() {
-        assert_eq!(part2(TEST_INPUT), 11387);
+        assert_eq!(part2(&parse_input(TEST_INPUT).unwrap()), 11387);
     }
 }
@@ -0,0 +1,187 @@
+use crate::context::Context;
+use crate::solution::{Answer, Solution};
+use crate::utils::grid::Grid;
+use crate::utils::movement::Point;
+use crate::utils::parse::ParseError;
+use std::collections::{HashMap, HashSet};
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Parsed = Grid<char>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        Grid::parse(input, Some)
+    }
+
+    fn part1(&self, map: &Self::Parsed, context: &Context) -> Answer {
+        part1(map, context).into()
+    }
+
+    fn part2(&self, map: &Self::Parsed, context: &Context) -> Answer {
+        part2(map, context).into()
+    }
+}
+
+#[derive(PartialEq, Debug)]
+struct Node {
+    id: char,
+    position: Point,
+}
+
+#[derive(PartialEq, Eq, Debug, Hash)]
+struct AntiNode {
+    id: char,
+    position: Point,
+}
+
+fn part1(map: &Grid<char>, context: &Context) -> usize {
+    let nodes = get_node_locations(map);
+    let antinodes = calculate_antinodes(map, &nodes, false);
+
+    context.frame("antinodes", || draw_map(map, &antinodes));
+
+    unique_antinodes(&antinodes).len()
+}
+
+fn part2(map: &Grid<char>, context: &Context) -> usize {
+    let nodes = get_node_locations(map);
+    let antinodes = calculate_antinodes(map, &nodes, true);
+
+    context.frame("antinodes", || draw_map(map, &antinodes));
+
+    unique_antinodes(&antinodes).len()
+}
+
+fn draw_map(map: &Grid<char>, antinodes: &HashSet<AntiNode>) -> String {
+    let mut out = String::new();
+
+    map.iter().for_each(|(position, c)| {
+        let antinode = antinodes.iter().find(|n| n.position == position);
+
+        if antinode.is_some() {
+            out.push_str(&format!("({})", *c));
+        } else {
+            out.push_str(&format!(" {} ", *c));
+        }
+
+        if position.x as usize == map.width() - 1 {
+            out.push('\n');
+        }
+    });
+
+    out
+}
+
+fn get_node_locations(map: &Grid<char>) -> HashMap<char, Vec<Node>> {
+    let mut locations = HashMap::new();
+
+    map.iter().for_each(|(position, c)| {
+        if *c != '.' {
+            let node_locations: &mut Vec<Node> = locations.entry(*c).or_insert_with(Vec::new);
+
+            node_locations.push(Node { id: *c, position });
+        }
+    });
+
+    locations
+}
+
+fn unique_antinodes(antinodes: &HashSet<AntiNode>) -> HashSet<Point> {
+    antinodes.iter().map(|antinode| antinode.position).collect()
+}
+
+fn calculate_antinodes(
+    map: &Grid<char>,
+    nodes: &HashMap<char, Vec<Node>>,
+    with_harmonics: bool,
+) -> HashSet<AntiNode> {
+    let mut antinodes = HashSet::new();
+
+    for (_, node_group) in nodes.iter() {
+        for node in node_group {
+            node_group.iter().for_each(|inner_node| {
+                if inner_node == node {
+                    return;
+                }
+
+                let diff: Point = node.position - inner_node.position;
+
+                if diff == Point::default() {
+                    return;
+                }
+
+                if with_harmonics {
+                    let mut position = node.position + diff;
+
+                    while map.contains(position) {
+                        antinodes.insert(AntiNode {
+                            id: node.id,
+                            position,
+                        });
+
+                        position += diff;
+                    }
+
+                    position = node.position - diff;
+
+                    while map.contains(position) {
+                        antinodes.insert(AntiNode {
+                            id: node.id,
+                            position,
+                        });
+
+                        position -= diff;
+                    }
+                } else {
+                    let antinode1 = AntiNode {
+                        id: node.id,
+                        position: node.position + diff,
+                    };
+
+                    let antinode2 = AntiNode {
+                        id: node.id,
+                        position: node.position - diff,
+                    };
+
+                    if map.contains(antinode1.position) && !overlaps_node(node, &antinode1) {
+                        antinodes.insert(antinode1);
+                    }
+
+                    if map.contains(antinode2.position) && !overlaps_node(inner_node, &antinode2) {
+                        antinodes.insert(antinode2);
+                    }
+                }
+            })
+        }
+    }
+
+    antinodes
+}
+
+fn overlaps_node(current_node: &Node, antinode: &AntiNode) -> bool {
+    current_node.position == antinode.position
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = include_str!("../../inputs/2024/day_8/test");
+
+    #[test]
+    pub fn test_part1() {
+        assert_eq!(
+            part1(&Grid::parse(TEST_INPUT, Some).unwrap(), &Context::silent()),
+            14
+        );
+    }
+
+    #[test]
+    pub fn test_part2() {
+        assert_eq!(
+            part2(&Grid::parse(TEST_INPUT, Some).unwrap(), &Context::silent()),
+            34
+        );
+    }
+}
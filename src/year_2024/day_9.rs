@@ -1,28 +1,40 @@
-use crossterm::{cursor::Hide, cursor::MoveTo, QueueableCommand};
-use std::io::{stdout, Write};
-use std::str::FromStr;
 use std::vec;
 
-pub fn execute(input: &str) {
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
+use crate::context::Context;
+use crate::solution::{Answer, Solution};
+use crate::utils::parse::{self, ParseError};
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Parsed = Vec<Option<usize>>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&self, disk: &Self::Parsed, _: &Context) -> Answer {
+        part1(disk).into()
+    }
+
+    fn part2(&self, disk: &Self::Parsed, context: &Context) -> Answer {
+        part2(disk, context).into()
+    }
 }
-#[allow(dead_code)]
-fn part1(input: &str) -> usize {
-    let blocks = parse_input(input);
-    let fragmented_blocks = fragment(&blocks);
+
+fn part1(blocks: &Vec<Option<usize>>) -> usize {
+    let fragmented_blocks = fragment(blocks);
 
     checksum(&fragmented_blocks)
 }
 
-fn part2(input: &str) -> usize {
-    let blocks = parse_input(input);
-    let defragmented_blocks = defragment(&blocks);
+fn part2(blocks: &Vec<Option<usize>>, context: &Context) -> usize {
+    let defragmented_blocks = defragment(blocks, context);
 
     checksum(&defragmented_blocks)
 }
 
-fn parse_input(input: &str) -> Vec<Option<usize>> {
+fn parse_input(input: &str) -> Result<Vec<Option<usize>>, ParseError> {
     let mut disk = vec![];
     let bytes = input.as_bytes();
 
@@ -37,13 +49,13 @@ fn parse_input(input: &str) -> Vec<Option<usize>> {
         let chunk = &input[i..end].split_at(1);
 
         if !chunk.0.is_empty() {
-            for _ in 0..usize::from_str(chunk.0).unwrap() {
+            for _ in 0..parse::number::<usize>(input, chunk.0)? {
                 disk.push(Some(id));
             }
         }
 
         if !chunk.1.is_empty() {
-            for _ in 0..usize::from_str(chunk.1).unwrap() {
+            for _ in 0..parse::number::<usize>(input, chunk.1)? {
                 disk.push(None);
             }
         }
@@ -51,10 +63,9 @@ fn parse_input(input: &str) -> Vec<Option<usize>> {
         id += 1;
     }
 
-    disk
+    Ok(disk)
 }
 
-#[allow(dead_code)]
 fn fragment(disk: &Vec<Option<usize>>) -> Vec<Option<usize>> {
     let mut new_disk: Vec<Option<usize>> = disk.clone();
     let mut pointer = 0;
@@ -95,25 +106,22 @@ fn move_chunk(
     }
 }
 
-fn print_progress(disk: &Vec<Option<usize>>) {
-    let mut out = stdout();
-    out.queue(Hide).unwrap();
-    out.queue(MoveTo(0, 0)).unwrap();
-
-    disk.chunks(10).for_each(|chunk| {
-        let nones: Vec<_> = chunk.into_iter().filter(|x| x.is_none()).collect();
-
-        if nones.len() == chunk.len() {
-            out.write(".".as_bytes()).unwrap();
-        } else if nones.len() > 0 {
-            out.write("#".as_bytes()).unwrap();
-        } else {
-            out.write("█".as_bytes()).unwrap();
-        }
-    });
-
-    out.write("\n".as_bytes()).unwrap();
-    out.flush().unwrap();
+/// Draws every 10 blocks of the disk as one character, `.` when they are free, `#` when some of
+/// them are and `█` when they are all used.
+fn draw_progress(disk: &[Option<usize>]) -> String {
+    disk.chunks(10)
+        .map(|chunk| {
+            let nones = chunk.iter().filter(|x| x.is_none()).count();
+
+            if nones == chunk.len() {
+                '.'
+            } else if nones > 0 {
+                '#'
+            } else {
+                '█'
+            }
+        })
+        .collect()
 }
 
 fn find_free_space(size: usize, disk: &Vec<Option<usize>>) -> Option<usize> {
@@ -139,7 +147,7 @@ fn find_free_space(size: usize, disk: &Vec<Option<usize>>) -> Option<usize> {
     None
 }
 
-fn defragment(disk: &Vec<Option<usize>>) -> Vec<Option<usize>> {
+fn defragment(disk: &Vec<Option<usize>>, context: &Context) -> Vec<Option<usize>> {
     let mut new_disk: Vec<Option<usize>> = disk.clone();
     let mut current_id = disk.iter().max().unwrap().unwrap();
     let mut first_free_position = 0;
@@ -187,8 +195,8 @@ fn defragment(disk: &Vec<Option<usize>>) -> Vec<Option<usize>> {
                     free_start.unwrap(),
                 );
 
-                // Defragmentation ASMR (comment if you want to see the real puzzle output)
-                print_progress(&new_disk);
+                // Defragmentation ASMR
+                context.frame("defragmenting", || draw_progress(&new_disk));
 
                 first_free_position = new_disk.iter().position(|x| x.is_none()).unwrap();
 
@@ -225,15 +233,18 @@ fn checksum(disk: &Vec<Option<usize>>) -> usize {
 mod tests {
     use super::*;
 
-    const TEST_INPUT: &str = include_str!("../inputs/day_9/test");
+    const TEST_INPUT: &str = include_str!("../../inputs/2024/day_9/test");
 
     #[test]
     pub fn test_part1() {
-        assert_eq!(part1(TEST_INPUT), 1928);
+        assert_eq!(part1(&parse_input(TEST_INPUT).unwrap()), 1928);
     }
 
     #[test]
     pub fn test_part2() {
-        assert_eq!(part2(TEST_INPUT), 2858);
+        assert_eq!(
+            part2(&parse_input(TEST_INPUT).unwrap(), &Context::silent()),
+            2858
+        );
     }
 }
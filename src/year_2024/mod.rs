@@ -0,0 +1,55 @@
+use crate::registry::DAY_COUNT;
+use crate::solution::Runnable;
+
+pub mod day_1;
+pub mod day_10;
+pub mod day_11;
+pub mod day_12;
+pub mod day_13;
+pub mod day_14;
+pub mod day_15;
+pub mod day_16;
+pub mod day_17;
+pub mod day_18;
+pub mod day_19;
+pub mod day_2;
+pub mod day_20;
+pub mod day_22;
+pub mod day_23;
+pub mod day_24;
+pub mod day_25;
+pub mod day_3;
+pub mod day_4;
+pub mod day_5;
+pub mod day_6;
+pub mod day_7;
+pub mod day_8;
+pub mod day_9;
+
+pub const DAYS: [Option<&dyn Runnable>; DAY_COUNT] = [
+    Some(&day_1::Day1),
+    Some(&day_2::Day2),
+    Some(&day_3::Day3),
+    Some(&day_4::Day4),
+    Some(&day_5::Day5),
+    Some(&day_6::Day6),
+    Some(&day_7::Day7),
+    Some(&day_8::Day8),
+    Some(&day_9::Day9),
+    Some(&day_10::Day10),
+    Some(&day_11::Day11),
+    Some(&day_12::Day12),
+    Some(&day_13::Day13),
+    Some(&day_14::Day14),
+    Some(&day_15::Day15),
+    Some(&day_16::Day16),
+    Some(&day_17::Day17),
+    Some(&day_18::Day18),
+    Some(&day_19::Day19),
+    Some(&day_20::Day20),
+    None,
+    Some(&day_22::Day22),
+    None,
+    Some(&day_24::Day24),
+    Some(&day_25::Day25),
+];